@@ -0,0 +1,137 @@
+extern crate solution;
+
+use std::vec::Vec;
+use solution::Solution;
+
+/// Solution to day five: reacting the suit's polymer.
+pub struct DayFive {
+    polymer: Vec<u8>,
+    activated_polymer: Option<Vec<i8>>,
+}
+
+impl DayFive {
+    pub fn new() -> DayFive {
+        DayFive {
+            polymer: Vec::new(),
+            activated_polymer: None,
+        }
+    }
+
+    /// Returns the number of units in the original polymer.
+    pub fn original_len(&self) -> usize {
+        self.polymer.len()
+    }
+
+    /// Returns the polymer remaining once every reacting pair of units has been destroyed.
+    pub fn activate(&mut self) -> &Vec<i8> {
+        if self.activated_polymer.is_none() {
+            let polymer = &self.polymer;
+            let mut activated_polymer: Vec<i8> = Vec::new();
+
+            // Loop over each unit in the polymer.
+            for unit in polymer.iter() {
+                // Pop the last unit from the activated polymer for comparison
+                let prev_unit = activated_polymer.pop();
+
+                // If it's None, then the activated polymer is currently empty. So push on the
+                // current unit and move on.
+                if prev_unit.is_none() {
+                    activated_polymer.push(*unit as i8);
+                    continue;
+                }
+
+                // Otherwise, we need to get the current unit in the unactivated polymer for
+                // comparison (and unwrap the previous unit, because we now know it isn't None).
+                let prev_unit = prev_unit.unwrap();
+                let curr_unit = *unit as i8;
+                let diff = curr_unit - prev_unit;
+
+                // The distance between a lowercase letter and its capital is always 32 for
+                // ascii characters. Despite Rust using unicode strings, input polymers will
+                // always be ascii values, which are equivalent between unicode and ordinary ascii.
+                if diff.abs() == 32 {
+                    // The current pair gets destroyed. We've already popped the previous value,
+                    // so we can just skip the current value.
+                    continue;
+                }
+
+                // No destruction, so we can push the current value.
+                activated_polymer.push(prev_unit);
+                activated_polymer.push(curr_unit);
+            }
+            self.activated_polymer = Some(activated_polymer);
+        }
+        self.activated_polymer.as_ref().unwrap()
+    }
+
+    /// Returns the length of the activated polymer after filtering out `c` (and its capital) and
+    /// recompressing.
+    pub fn filtered_len(&mut self, c: char) -> usize {
+        let activated_polymer = self.activate();
+        let mut reactivated_polymer: Vec<i8> = Vec::new();
+        for unit in activated_polymer.iter() {
+            // New check: skip any instances of c (or the capital version of c)
+            let lower = c as i8;
+            let upper = lower - 32;
+            if *unit == lower || *unit == upper {
+                continue;
+            }
+            // Remaining logic is a copy of the loop in activate().
+            let prev_unit = reactivated_polymer.pop();
+            if prev_unit.is_none() {
+                reactivated_polymer.push(*unit);
+                continue;
+            }
+            let prev_unit = prev_unit.unwrap();
+            let curr_unit = *unit;
+            let diff = curr_unit - prev_unit;
+            if diff.abs() == 32 {
+                continue;
+            }
+            reactivated_polymer.push(prev_unit);
+            reactivated_polymer.push(curr_unit);
+        }
+        reactivated_polymer.len()
+    }
+
+    /// Returns the letter whose removal produces the shortest polymer, along with that length.
+    pub fn optimal_filter(&mut self) -> (char, usize) {
+        // Now that the original polymer is activated, we can try filtering out specific letters and
+        // recompressing.
+        let mut min_length = self.activate().len();
+        let mut min_char = '\0';
+        for c in ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+                  'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'].iter() {
+            let length = self.filtered_len(*c);
+            if length < min_length {
+                min_length = length;
+                min_char = *c;
+            }
+        }
+        (min_char, min_length)
+    }
+}
+
+impl Default for DayFive {
+    fn default() -> DayFive {
+        DayFive::new()
+    }
+}
+
+impl Solution for DayFive {
+    fn parse(&mut self, input: &str) -> Result<(), String> {
+        // Input files for this problem should only have one polymer (i.e. one line).
+        let poly_string = input.lines().next().unwrap_or("");
+        self.polymer = poly_string.as_bytes().to_vec();
+        self.activated_polymer = None;
+        Ok(())
+    }
+
+    fn part_one(&mut self) -> String {
+        self.activate().len().to_string()
+    }
+
+    fn part_two(&mut self) -> String {
+        self.optimal_filter().1.to_string()
+    }
+}
@@ -0,0 +1,31 @@
+extern crate day_five_2018;
+extern crate input;
+extern crate solution;
+
+use std::env;
+use std::process;
+
+use day_five_2018::DayFive;
+use solution::Solution;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    assert_eq!(args.len(), 2, "Incorrect number of args");
+    let input = input::load_file(args[1].as_str()).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let mut day = DayFive::new();
+    day.parse(&input).unwrap();
+    println!("Orignal polymer had {} units, activated polymer has {} remaining",
+             day.original_len(), day.activate().len());
+
+    // Now that the original polymer is activated, we can try filtering out specific letters and recompressing.
+    for c in ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+              'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'].iter() {
+        println!("Filtering out {} and recompressing resulted in {} units", c, day.filtered_len(*c));
+    }
+    let (min_char, min_length) = day.optimal_filter();
+    println!("The optimal character to filter out was {}, and resulted in a new length of {}", min_char, min_length);
+}
@@ -0,0 +1,240 @@
+extern crate solution;
+
+mod log;
+mod profile;
+mod timestamp;
+mod validate;
+
+use std::vec::Vec;
+use solution::Solution;
+
+pub use log::Event;
+pub use log::GuardId;
+pub use log::LogEntry;
+pub use profile::sleep_profiles;
+pub use profile::SleepProfile;
+pub use timestamp::Timestamp;
+pub use validate::validate;
+pub use validate::LogProblem;
+pub use validate::ProblemKind;
+
+/// (guard id, total minutes asleep, most slept minute)
+pub type MaxGuard = (GuardId, u64, usize);
+/// (minute, times asleep on that minute, guard id)
+pub type MaxMinute = (usize, u32, GuardId);
+
+/// Solution to day four: finding the sleepiest guard to sneak past.
+pub struct DayFour {
+    /// The guard log entries, in chronological order.
+    entries: Vec<LogEntry>,
+    /// The line of the log each entry was on, counting from 1.
+    lines: Vec<usize>,
+    /// The sleep profile of each guard, once the log has been scanned.
+    profiles: Option<Vec<SleepProfile>>,
+}
+
+impl DayFour {
+    pub fn new() -> DayFour {
+        DayFour {
+            entries: Vec::new(),
+            lines: Vec::new(),
+            profiles: None,
+        }
+    }
+
+    /// Returns the log entries, in chronological order.
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+
+    /// Returns the inconsistencies in the log, in chronological order.
+    pub fn problems(&self) -> Vec<LogProblem> {
+        let entries: Vec<(LogEntry, usize)> = self.entries.iter().cloned().zip(self.lines.iter().cloned()).collect();
+        validate(&entries)
+    }
+
+    /// Returns the sleep profile of every guard that began a shift, in increasing order of guard ID.
+    pub fn profiles(&mut self) -> &[SleepProfile] {
+        if self.profiles.is_none() {
+            self.profiles = Some(sleep_profiles(&self.entries));
+        }
+        self.profiles.as_ref().unwrap()
+    }
+
+    /// Returns the guard that slept the most as `(guard id, total minutes asleep, most slept minute)`,
+    /// or `(0, 0, 0)` if no guard slept.
+    ///
+    /// Ties go to the guard with the lowest ID, and then to the earliest minute.
+    pub fn sleepiest_guard(&mut self) -> MaxGuard {
+        self.profiles().iter()
+            .filter_map(|profile| profile.most_slept_minute().map(|(minute, _)| (profile.guard, profile.total, minute)))
+            .fold((0, 0, 0), |best, guard| if guard.1 > best.1 { guard } else { best })
+    }
+
+    /// Returns the guard that slept the most on a specific minute as
+    /// `(minute, times asleep on that minute, guard id)`, or `(0, 0, 0)` if no guard slept.
+    ///
+    /// Ties go to the guard with the lowest ID, and then to the earliest minute.
+    pub fn sleepiest_minute(&mut self) -> MaxMinute {
+        self.profiles().iter()
+            .filter_map(|profile| profile.most_slept_minute().map(|(minute, times)| (minute, times, profile.guard)))
+            .fold((0, 0, 0), |best, minute| if minute.1 > best.1 { minute } else { best })
+    }
+}
+
+impl Default for DayFour {
+    fn default() -> DayFour {
+        DayFour::new()
+    }
+}
+
+impl Solution for DayFour {
+    fn parse(&mut self, input: &str) -> Result<(), String> {
+        self.profiles = None;
+
+        let mut entries = Vec::new();
+        for (line, number) in input.lines().zip(1..) {
+            entries.push((line.parse::<LogEntry>()?, number));
+        }
+        // Entries with the same timestamp stay in the order they were logged.
+        entries.sort_by_key(|&(entry, line)| (entry.timestamp, line));
+        self.entries = entries.iter().map(|&(entry, _)| entry).collect();
+        self.lines = entries.iter().map(|&(_, line)| line).collect();
+        Ok(())
+    }
+
+    fn part_one(&mut self) -> String {
+        let (guard, _, minute) = self.sleepiest_guard();
+        (u64::from(guard) * minute as u64).to_string()
+    }
+
+    fn part_two(&mut self) -> String {
+        let (minute, _, guard) = self.sleepiest_minute();
+        (u64::from(guard) * minute as u64).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solution::Solution;
+    use super::DayFour;
+    use super::ProblemKind;
+
+    const EXAMPLE: &str = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    fn day(log: &str) -> DayFour {
+        let mut day = DayFour::new();
+        day.parse(log).unwrap();
+        day
+    }
+
+    #[test]
+    fn solves_example() {
+        // Guard 99 starts their first shift at 23:58 the day before.
+        let mut day = day(EXAMPLE);
+        assert_eq!(day.sleepiest_guard(), (10, 50, 24));
+        assert_eq!(day.sleepiest_minute(), (45, 3, 99));
+        assert_eq!(day.part_one(), "240");
+        assert_eq!(day.part_two(), "4455");
+    }
+
+    #[test]
+    fn counts_sleep_across_hours_and_midnight() {
+        let mut day = day("[1518-11-01 23:58] Guard #7 begins shift
+[1518-11-02 00:30] falls asleep
+[1518-11-02 02:00] wakes up
+[1518-11-02 23:50] Guard #8 begins shift
+[1518-11-02 23:59] falls asleep
+[1518-11-03 00:02] wakes up
+[1518-11-03 23:45] Guard #8 begins shift
+[1518-11-03 23:59] falls asleep
+[1518-11-04 00:01] wakes up");
+        // Guard 7's 90 minute nap covers minutes 30 to 59 twice.
+        assert_eq!(day.sleepiest_guard(), (7, 90, 30));
+        assert_eq!(day.sleepiest_minute(), (30, 2, 7));
+        assert_eq!(day.part_one(), "210");
+    }
+
+    #[test]
+    fn counts_naps_lasting_days() {
+        let mut day = day("[1518-11-01 23:58] Guard #3 begins shift
+[1518-11-02 00:10] falls asleep
+[1518-11-04 00:15] wakes up
+[1518-11-05 00:00] Guard #4 begins shift
+[1518-11-05 00:10] falls asleep
+[1518-11-05 00:50] wakes up");
+        // Guard 3 sleeps through minutes 10 to 14 49 times, and every other minute 48 times.
+        assert_eq!(day.sleepiest_guard(), (3, 2 * 24 * 60 + 5, 10));
+        assert_eq!(day.sleepiest_minute(), (10, 49, 3));
+    }
+
+    #[test]
+    fn finds_most_slept_minute_after_guard_takes_the_lead() {
+        // Guard 2 only overtakes guard 1 on their second nap, after sleeping through minutes 30 to
+        // 34, which stay their most slept minutes.
+        let mut day = day("[1518-11-01 00:00] Guard #1 begins shift
+[1518-11-01 00:10] falls asleep
+[1518-11-01 00:20] wakes up
+[1518-11-02 00:00] Guard #2 begins shift
+[1518-11-02 00:30] falls asleep
+[1518-11-02 00:35] wakes up
+[1518-11-03 00:00] Guard #2 begins shift
+[1518-11-03 00:40] falls asleep
+[1518-11-03 00:50] wakes up");
+        assert_eq!(day.sleepiest_guard(), (2, 15, 30));
+        assert_eq!(day.part_one(), "60");
+    }
+
+    #[test]
+    fn profiles_guards() {
+        let mut day = day(EXAMPLE);
+        let profiles = day.profiles();
+        assert_eq!(profiles.iter().map(|profile| profile.guard).collect::<Vec<_>>(), vec![10, 99]);
+        assert_eq!((profiles[0].shifts, profiles[0].total), (2, 50));
+        assert_eq!(profiles[0].most_slept_minute(), Some((24, 2)));
+        assert_eq!(profiles[0].average_sleep_per_shift(), 25.0);
+        assert_eq!((profiles[1].shifts, profiles[1].total), (3, 30));
+        assert_eq!(profiles[1].per_minute[45], 3);
+        assert_eq!(profiles[1].average_sleep_per_shift(), 10.0);
+    }
+
+    #[test]
+    fn handles_guards_that_never_sleep() {
+        let mut day = day("[1518-11-01 00:00] Guard #5 begins shift");
+        assert_eq!(day.profiles()[0].most_slept_minute(), None);
+        assert_eq!(day.sleepiest_guard(), (0, 0, 0));
+        assert_eq!(day.sleepiest_minute(), (0, 0, 0));
+    }
+
+    #[test]
+    fn keeps_simultaneous_entries_in_log_order() {
+        let mut day = day("[1518-11-01 00:05] falls asleep
+[1518-11-01 00:05] wakes up
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:10] wakes up");
+        // The sleep and wake-up share timestamps, but aren't out of order.
+        let problems: Vec<(usize, ProblemKind)> = day.problems().iter()
+            .map(|problem| (problem.line, problem.kind))
+            .collect();
+        assert_eq!(problems, vec![(2, ProblemKind::DuplicateTimestamp), (4, ProblemKind::DuplicateTimestamp)]);
+        assert_eq!(day.sleepiest_guard(), (10, 5, 5));
+        assert_eq!(day.sleepiest_minute(), (5, 1, 10));
+    }
+}
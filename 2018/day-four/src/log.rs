@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use timestamp::Timestamp;
+
+/// A guard's ID number.
+pub type GuardId = u32;
+
+/// Something that happened at a guard post.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Event {
+    /// The given guard began their shift.
+    BeginShift(GuardId),
+    /// The guard on duty fell asleep.
+    FallAsleep,
+    /// The guard on duty woke up.
+    WakeUp,
+}
+
+/// An entry in the guard post's log, e.g. `[1518-11-01 00:00] Guard #10 begins shift`.
+///
+/// Entries are ordered by their timestamps, and entries with the same timestamp by their events.
+/// To put a log in chronological order without reordering entries with the same timestamp, sort
+/// it stably by timestamp alone.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LogEntry {
+    pub timestamp: Timestamp,
+    pub event: Event,
+}
+
+impl FromStr for LogEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<LogEntry, String> {
+        let invalid = || format!("invalid log entry '{}'", s);
+        let rest = s.strip_prefix('[').ok_or_else(invalid)?;
+        let (timestamp, event) = rest.split_once("] ").ok_or_else(invalid)?;
+        let timestamp = timestamp.parse().map_err(|e| format!("invalid log entry '{}': {}", s, e))?;
+        let event = match event {
+            "falls asleep" => Event::FallAsleep,
+            "wakes up" => Event::WakeUp,
+            _ => {
+                let guard = event.strip_prefix("Guard #")
+                    .and_then(|event| event.strip_suffix(" begins shift"))
+                    .ok_or_else(|| format!("unknown action in '{}'", s))?;
+                if guard.is_empty() || !guard.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                Event::BeginShift(guard.parse().map_err(|_| invalid())?)
+            }
+        };
+        Ok(LogEntry { timestamp, event })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use timestamp::Timestamp;
+    use super::Event;
+    use super::LogEntry;
+
+    fn entry(s: &str) -> LogEntry {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_entries() {
+        let timestamp = Timestamp { year: 1518, month: 11, day: 1, hour: 0, minute: 5 };
+        assert_eq!(entry("[1518-11-01 00:05] Guard #10 begins shift"),
+                   LogEntry { timestamp, event: Event::BeginShift(10) });
+        assert_eq!(entry("[1518-11-01 00:05] falls asleep"), LogEntry { timestamp, event: Event::FallAsleep });
+        assert_eq!(entry("[1518-11-01 00:05] wakes up"), LogEntry { timestamp, event: Event::WakeUp });
+
+        assert_eq!("[1518-11-01 00:05] dozes off".parse::<LogEntry>(),
+                   Err(String::from("unknown action in '[1518-11-01 00:05] dozes off'")));
+        assert_eq!("[1518-11-01 00:65] wakes up".parse::<LogEntry>(),
+                   Err(String::from("invalid log entry '[1518-11-01 00:65] wakes up': \
+                                     invalid timestamp '1518-11-01 00:65'")));
+        for invalid in ["", "1518-11-01 00:05 wakes up", "[1518-11-01 00:05]wakes up",
+                        "[1518-11-01 00:05] Guard # begins shift", "[1518-11-01 00:05] Guard #-1 begins shift"].iter() {
+            assert_eq!(invalid.parse::<LogEntry>(), Err(format!("invalid log entry '{}'", invalid)));
+        }
+    }
+
+    #[test]
+    fn orders_consistently() {
+        let shift = entry("[1518-11-01 00:00] Guard #10 begins shift");
+        let other_shift = entry("[1518-11-01 00:00] Guard #99 begins shift");
+        let asleep = entry("[1518-11-01 00:00] falls asleep");
+        let earlier = entry("[1518-10-31 23:59] wakes up");
+        // Entries are only equal if they're identical, and only compare as equal if they're equal.
+        assert_ne!(shift, other_shift);
+        assert!(shift < other_shift);
+        assert!(shift < asleep);
+        assert!(earlier < shift);
+        let mut entries = vec![asleep, other_shift, shift, earlier];
+        entries.sort();
+        assert_eq!(entries, vec![earlier, shift, other_shift, asleep]);
+    }
+}
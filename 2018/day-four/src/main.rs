@@ -0,0 +1,47 @@
+extern crate day_four_2018;
+extern crate input;
+extern crate solution;
+
+use std::env;
+use std::process;
+
+use day_four_2018::DayFour;
+use solution::Solution;
+
+fn main() {
+    // Usage: day-four-2018 <input> [--strict]
+    // Any inconsistencies in the log are reported before the answers; --strict stops with an
+    // error instead of answering.
+    let args: Vec<String> = env::args().collect();
+    let mut path = None;
+    let mut strict = false;
+    for arg in args.iter().skip(1) {
+        if arg == "--strict" {
+            strict = true;
+        } else {
+            assert!(path.is_none(), "Incorrect number of args");
+            path = Some(arg);
+        }
+    }
+    let input = input::load_file(path.expect("Incorrect number of args")).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let mut day = DayFour::new();
+    day.parse(&input).unwrap();
+    let problems = day.problems();
+    for problem in problems.iter() {
+        eprintln!("Warning: {}", problem);
+    }
+    if strict && !problems.is_empty() {
+        eprintln!("Found {} problems in the log", problems.len());
+        process::exit(1);
+    }
+    let max_guard = day.sleepiest_guard();
+    let max_minute = day.sleepiest_minute();
+    println!("Guard {} slept the most ({} minutes), and they slept most frequently during minute {}",
+             max_guard.0, max_guard.1, max_guard.2);
+    println!("The guard that slept the most on a specific minute was guard {}, on minute {}",
+             max_minute.2, max_minute.0);
+}
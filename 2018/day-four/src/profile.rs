@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+use log::Event;
+use log::GuardId;
+use log::LogEntry;
+use timestamp::Timestamp;
+
+/// How much, and when, a guard slept on duty.
+///
+/// Naps are measured from full timestamps, so they may cross the hour, midnight or even several
+/// days. Every minute a guard spends asleep counts towards its minute of the hour, whichever hour
+/// it falls in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SleepProfile {
+    pub guard: GuardId,
+    /// The number of shifts the guard began.
+    pub shifts: usize,
+    /// The total number of minutes the guard was asleep.
+    pub total: u64,
+    /// The number of times the guard was asleep during each minute of the hour.
+    pub per_minute: [u32; 60],
+}
+
+impl SleepProfile {
+    fn new(guard: GuardId) -> SleepProfile {
+        SleepProfile { guard, shifts: 0, total: 0, per_minute: [0; 60] }
+    }
+
+    /// Returns the minute the guard was most often asleep during, and how many times, or `None`
+    /// if they never slept. Ties go to the earliest minute.
+    pub fn most_slept_minute(&self) -> Option<(usize, u32)> {
+        let (minute, times) = self.per_minute.iter().cloned().enumerate()
+            .fold((0, 0), |best, (minute, times)| if times > best.1 { (minute, times) } else { best });
+        if times > 0 { Some((minute, times)) } else { None }
+    }
+
+    /// Returns the average number of minutes the guard slept per shift.
+    pub fn average_sleep_per_shift(&self) -> f64 {
+        if self.shifts == 0 { 0.0 } else { self.total as f64 / self.shifts as f64 }
+    }
+}
+
+/// Splits the time from `sleep` until `wake` by minute of the hour, as `(minute, times)` pairs:
+/// a nap from 23:50 to 01:05 covers minutes 50 to 59 twice, and every other minute once.
+///
+/// The pairs start at the minute the guard fell asleep and go round the hour from there, leaving
+/// out minutes the guard never slept through.
+pub(crate) fn minutes_asleep(sleep: &Timestamp, wake: &Timestamp) -> Vec<(usize, u32)> {
+    let duration = sleep.minutes_until(wake).max(0);
+    let (full_hours, rest) = (duration / 60, duration % 60);
+    (0..duration.min(60))
+        .map(|offset| {
+            let minute = (sleep.minute as usize + offset as usize) % 60;
+            (minute, (full_hours + if offset < rest { 1 } else { 0 }) as u32)
+        })
+        .collect()
+}
+
+/// Builds the sleep profile of every guard that began a shift.
+///
+/// # Arguments
+///
+/// * `entries` - The log entries, in chronological order. Naps are only counted when a guard
+///   falls asleep and the next entry has them waking up, and only once a shift has begun.
+///
+/// # Returns
+///
+/// * A vector of profiles, in increasing order of guard ID.
+///
+/// # Example
+///
+/// ```
+/// use day_four_2018::sleep_profiles;
+/// use day_four_2018::LogEntry;
+///
+/// let entries: Vec<LogEntry> = ["[1518-11-01 00:00] Guard #10 begins shift",
+///                               "[1518-11-01 00:05] falls asleep",
+///                               "[1518-11-01 00:25] wakes up",
+///                               "[1518-11-01 23:58] Guard #99 begins shift",
+///                               "[1518-11-02 23:59] Guard #10 begins shift"].iter()
+///     .map(|entry| entry.parse().unwrap())
+///     .collect();
+/// let profiles = sleep_profiles(&entries);
+/// assert_eq!(profiles.len(), 2);
+/// assert_eq!(profiles[0].guard, 10);
+/// assert_eq!(profiles[0].total, 20);
+/// assert_eq!(profiles[0].most_slept_minute(), Some((5, 1)));
+/// assert_eq!(profiles[0].average_sleep_per_shift(), 10.0);
+/// assert_eq!(profiles[1].most_slept_minute(), None);
+/// ```
+pub fn sleep_profiles(entries: &[LogEntry]) -> Vec<SleepProfile> {
+    let mut profiles: BTreeMap<GuardId, SleepProfile> = BTreeMap::new();
+    let mut active_guard = None;
+    let mut prev: Option<&LogEntry> = None;
+    for entry in entries.iter() {
+        match (entry.event, active_guard, prev.map(|prev| (prev.event, prev.timestamp))) {
+            (Event::BeginShift(guard), _, _) => {
+                active_guard = Some(guard);
+                profiles.entry(guard).or_insert_with(|| SleepProfile::new(guard)).shifts += 1;
+            },
+            (Event::WakeUp, Some(guard), Some((Event::FallAsleep, sleep))) => {
+                let profile = profiles.get_mut(&guard).unwrap();
+                profile.total += sleep.minutes_until(&entry.timestamp).max(0) as u64;
+                for (minute, times) in minutes_asleep(&sleep, &entry.timestamp) {
+                    profile.per_minute[minute] += times;
+                }
+            },
+            _ => {}
+        }
+        prev = Some(entry);
+    }
+    profiles.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use timestamp::Timestamp;
+    use super::minutes_asleep;
+
+    fn timestamp(s: &str) -> Timestamp {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn splits_minutes_by_hour() {
+        let asleep = minutes_asleep(&timestamp("1518-11-01 00:05"), &timestamp("1518-11-01 00:08"));
+        assert_eq!(asleep, vec![(5, 1), (6, 1), (7, 1)]);
+        let asleep = minutes_asleep(&timestamp("1518-11-01 23:58"), &timestamp("1518-11-02 00:01"));
+        assert_eq!(asleep, vec![(58, 1), (59, 1), (0, 1)]);
+        let asleep = minutes_asleep(&timestamp("1518-11-01 23:50"), &timestamp("1518-11-02 01:05"));
+        assert_eq!(asleep.len(), 60);
+        assert_eq!(asleep[0], (50, 2));
+        assert_eq!(asleep[14], (4, 2));
+        assert_eq!(asleep[15], (5, 1));
+        assert_eq!(asleep.iter().map(|&(_, times)| times).sum::<u32>(), 75);
+        assert!(minutes_asleep(&timestamp("1518-11-01 00:05"), &timestamp("1518-11-01 00:05")).is_empty());
+    }
+}
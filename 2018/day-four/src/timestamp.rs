@@ -0,0 +1,191 @@
+use std::fmt;
+use std::str::FromStr;
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// A time of day on a (proleptic Gregorian) calendar date, to the minute, e.g. `1518-11-01 00:05`.
+///
+/// The fields are ordered from most to least significant, so the derived ordering is
+/// chronological.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to the given date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Count from the 1st of March, so that leap days fall at the end of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((i64::from(month) + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the date the given number of days after 1970-01-01, as `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+impl Timestamp {
+    /// Returns the number of minutes from 1970-01-01 00:00 to this timestamp (which is negative
+    /// for earlier timestamps).
+    pub fn to_minutes(&self) -> i64 {
+        let days = days_from_civil(i64::from(self.year), self.month, self.day);
+        days * MINUTES_PER_DAY + i64::from(self.hour * 60 + self.minute)
+    }
+
+    /// Returns the timestamp the given number of minutes after 1970-01-01 00:00.
+    pub fn from_minutes(minutes: i64) -> Timestamp {
+        let (year, month, day) = civil_from_days(minutes.div_euclid(MINUTES_PER_DAY));
+        let minute_of_day = minutes.rem_euclid(MINUTES_PER_DAY) as u32;
+        Timestamp { year: year as i32, month, day, hour: minute_of_day / 60, minute: minute_of_day % 60 }
+    }
+
+    /// Returns the timestamp `minutes` minutes after this one (or before it, if negative).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use day_four_2018::Timestamp;
+    ///
+    /// let timestamp: Timestamp = "1518-02-28 23:58".parse().unwrap();
+    /// assert_eq!(timestamp.add_minutes(3).to_string(), "1518-03-01 00:01");
+    /// assert_eq!(timestamp.add_minutes(-60 * 24 * 59).to_string(), "1517-12-31 23:58");
+    /// ```
+    pub fn add_minutes(&self, minutes: i64) -> Timestamp {
+        Timestamp::from_minutes(self.to_minutes() + minutes)
+    }
+
+    /// Returns the number of minutes from this timestamp until `later` (which is negative if
+    /// `later` is actually earlier).
+    pub fn minutes_until(&self, later: &Timestamp) -> i64 {
+        later.to_minutes() - self.to_minutes()
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02} {:02}:{:02}", self.year, self.month, self.day, self.hour, self.minute)
+    }
+}
+
+/// Parses a number of exactly `digits` decimal digits.
+fn parse_digits(s: &str, digits: usize) -> Option<u32> {
+    if s.len() != digits || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+impl FromStr for Timestamp {
+    type Err = String;
+
+    /// Parses a timestamp in the form `YYYY-MM-DD hh:mm`.
+    fn from_str(s: &str) -> Result<Timestamp, String> {
+        let invalid = || format!("invalid timestamp '{}'", s);
+        let (date, time) = s.split_once(' ').ok_or_else(invalid)?;
+        let date: Vec<&str> = date.split('-').collect();
+        let time: Vec<&str> = time.split(':').collect();
+        if date.len() != 3 || time.len() != 2 {
+            return Err(invalid());
+        }
+        let field = |part: &str, digits| parse_digits(part, digits).ok_or_else(invalid);
+        let timestamp = Timestamp {
+            year: field(date[0], 4)? as i32,
+            month: field(date[1], 2)?,
+            day: field(date[2], 2)?,
+            hour: field(time[0], 2)?,
+            minute: field(time[1], 2)?,
+        };
+        let valid = (1..=12).contains(&timestamp.month)
+            && (1..=days_in_month(i64::from(timestamp.year), timestamp.month)).contains(&timestamp.day)
+            && timestamp.hour < 24
+            && timestamp.minute < 60;
+        if valid { Ok(timestamp) } else { Err(invalid()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timestamp;
+
+    fn timestamp(s: &str) -> Timestamp {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_timestamps() {
+        assert_eq!(timestamp("1518-11-01 00:05"), Timestamp { year: 1518, month: 11, day: 1, hour: 0, minute: 5 });
+        assert_eq!(timestamp("2000-02-29 23:59").to_string(), "2000-02-29 23:59");
+        for invalid in ["", "1518-11-01", "1518-11-01 0:05", "1518-13-01 00:05", "1518-02-29 00:05",
+                        "1900-02-29 00:05", "1518-11-01 24:00", "1518-11-01 00:60", "1518-11-01 00:05:00",
+                        "1518-11-01-01 00:05", "1518-+1-01 00:05"].iter() {
+            assert_eq!(invalid.parse::<Timestamp>(), Err(format!("invalid timestamp '{}'", invalid)));
+        }
+    }
+
+    #[test]
+    fn orders_chronologically() {
+        let mut timestamps = [timestamp("1518-11-01 00:05"), timestamp("1518-10-31 23:58"),
+                               timestamp("1517-12-31 23:59"), timestamp("1518-11-01 00:04")];
+        timestamps.sort();
+        let minutes: Vec<i64> = timestamps.iter().map(|timestamp| timestamp.to_minutes()).collect();
+        let mut sorted = minutes.clone();
+        sorted.sort();
+        assert_eq!(minutes, sorted);
+        assert_eq!(timestamps[0], timestamp("1517-12-31 23:59"));
+    }
+
+    #[test]
+    fn converts_minutes() {
+        assert_eq!(timestamp("1970-01-01 00:00").to_minutes(), 0);
+        assert_eq!(timestamp("1970-01-02 01:01").to_minutes(), 24 * 60 + 61);
+        assert_eq!(timestamp("1969-12-31 23:59").to_minutes(), -1);
+        // Every minute maps back to the same timestamp, across month, year and leap day boundaries.
+        let start = timestamp("1518-01-01 00:00").to_minutes();
+        for day in 0..(4 * 366) {
+            let minutes = start + day * 24 * 60 + day % (24 * 60);
+            let converted = Timestamp::from_minutes(minutes);
+            // Parsing checks that the date is a real one.
+            assert_eq!(timestamp(&converted.to_string()).to_minutes(), minutes);
+        }
+    }
+
+    #[test]
+    fn counts_minutes_between() {
+        let start = timestamp("1518-11-01 23:58");
+        assert_eq!(start.minutes_until(&timestamp("1518-11-02 00:40")), 42);
+        assert_eq!(timestamp("1518-11-02 00:40").minutes_until(&start), -42);
+        assert_eq!(start.add_minutes(42), timestamp("1518-11-02 00:40"));
+        assert_eq!(timestamp("1518-12-31 23:59").add_minutes(1), timestamp("1519-01-01 00:00"));
+        assert_eq!(timestamp("1600-02-28 12:00").add_minutes(24 * 60), timestamp("1600-02-29 12:00"));
+    }
+}
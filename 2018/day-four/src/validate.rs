@@ -0,0 +1,155 @@
+use std::fmt;
+
+use log::Event;
+use log::LogEntry;
+use timestamp::Timestamp;
+
+/// The ways a guard log can be inconsistent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProblemKind {
+    /// A guard falls asleep or wakes up before any shift has begun.
+    NoGuardOnDuty,
+    /// The guard on duty falls asleep while already asleep.
+    AlreadyAsleep,
+    /// The guard on duty wakes up without having fallen asleep.
+    NotAsleep,
+    /// A shift begins while the previous guard is still asleep.
+    ShiftWhileAsleep,
+    /// The guard on duty falls asleep and the log ends before they wake up.
+    NeverWakes,
+    /// The entry has the same timestamp as the one before it.
+    DuplicateTimestamp,
+}
+
+/// An inconsistency in a guard log.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LogProblem {
+    /// The line of the log the offending entry is on, counting from 1.
+    pub line: usize,
+    /// The timestamp of the offending entry.
+    pub timestamp: Timestamp,
+    pub kind: ProblemKind,
+}
+
+impl fmt::Display for LogProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self.kind {
+            ProblemKind::NoGuardOnDuty => "no guard is on duty yet",
+            ProblemKind::AlreadyAsleep => "the guard falls asleep while already asleep",
+            ProblemKind::NotAsleep => "the guard wakes up without falling asleep",
+            ProblemKind::ShiftWhileAsleep => "the shift begins while the previous guard is asleep",
+            ProblemKind::NeverWakes => "the guard never wakes up",
+            ProblemKind::DuplicateTimestamp => "another entry has the same timestamp",
+        };
+        write!(f, "line {} [{}]: {}", self.line, self.timestamp, description)
+    }
+}
+
+/// Checks a guard log for inconsistencies.
+///
+/// # Arguments
+///
+/// * `entries` - The log entries in chronological order, each with the line it was on.
+///
+/// # Returns
+///
+/// * A vector of the problems found, in chronological order.
+pub fn validate(entries: &[(LogEntry, usize)]) -> Vec<LogProblem> {
+    let mut problems = Vec::new();
+    let mut on_duty = false;
+    // When and on which line the guard on duty fell asleep, if they're asleep.
+    let mut asleep: Option<(Timestamp, usize)> = None;
+    let mut prev: Option<Timestamp> = None;
+    for &(ref entry, line) in entries.iter() {
+        let mut report = |kind| problems.push(LogProblem { line, timestamp: entry.timestamp, kind });
+        if prev == Some(entry.timestamp) {
+            report(ProblemKind::DuplicateTimestamp);
+        }
+        match entry.event {
+            Event::BeginShift(_) => {
+                if asleep.is_some() {
+                    report(ProblemKind::ShiftWhileAsleep);
+                }
+                on_duty = true;
+                asleep = None;
+            },
+            Event::FallAsleep => {
+                if !on_duty {
+                    // Nobody is there to be asleep, so this doesn't affect the following entries.
+                    report(ProblemKind::NoGuardOnDuty);
+                } else {
+                    if asleep.is_some() {
+                        report(ProblemKind::AlreadyAsleep);
+                    }
+                    asleep = Some((entry.timestamp, line));
+                }
+            },
+            Event::WakeUp => {
+                if !on_duty {
+                    report(ProblemKind::NoGuardOnDuty);
+                } else if asleep.is_none() {
+                    report(ProblemKind::NotAsleep);
+                }
+                asleep = None;
+            }
+        }
+        prev = Some(entry.timestamp);
+    }
+    if let Some((timestamp, line)) = asleep {
+        problems.push(LogProblem { line, timestamp, kind: ProblemKind::NeverWakes });
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use log::LogEntry;
+    use super::validate;
+    use super::ProblemKind;
+
+    /// Parses and sorts a log, and returns the line and kind of each problem in it.
+    fn problems(log: &str) -> Vec<(usize, ProblemKind)> {
+        let mut entries: Vec<(LogEntry, usize)> = log.lines().zip(1..)
+            .map(|(line, number)| (line.parse().unwrap(), number))
+            .collect();
+        entries.sort_by_key(|&(entry, line)| (entry.timestamp, line));
+        validate(&entries).iter().map(|problem| (problem.line, problem.kind)).collect()
+    }
+
+    #[test]
+    fn accepts_consistent_log() {
+        assert!(problems("[1518-11-01 00:30] falls asleep
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift").is_empty());
+    }
+
+    #[test]
+    fn reports_problems() {
+        let problems = problems("[1518-11-01 00:00] falls asleep
+[1518-11-01 00:05] Guard #10 begins shift
+[1518-11-01 00:10] wakes up
+[1518-11-01 00:20] falls asleep
+[1518-11-01 00:25] falls asleep
+[1518-11-01 00:30] wakes up
+[1518-11-02 00:00] Guard #11 begins shift
+[1518-11-02 00:00] falls asleep
+[1518-11-02 00:05] Guard #12 begins shift
+[1518-11-02 00:10] falls asleep");
+        assert_eq!(problems, vec![
+            (1, ProblemKind::NoGuardOnDuty),
+            (3, ProblemKind::NotAsleep),
+            (5, ProblemKind::AlreadyAsleep),
+            (8, ProblemKind::DuplicateTimestamp),
+            (9, ProblemKind::ShiftWhileAsleep),
+            (10, ProblemKind::NeverWakes),
+        ]);
+    }
+
+    #[test]
+    fn describes_problems() {
+        let entries = vec![("[1518-11-01 00:10] wakes up".parse::<LogEntry>().unwrap(), 4)];
+        let problems: Vec<String> = validate(&entries).iter().map(|problem| problem.to_string()).collect();
+        assert_eq!(problems, vec!["line 4 [1518-11-01 00:10]: no guard is on duty yet"]);
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// The result of searching for the first frequency reached twice.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RepeatOutcome {
+    /// The first frequency reached twice.
+    Repeat(i128),
+    /// No frequency is ever reached twice, no matter how many passes are made.
+    NoRepeat,
+    /// A simulated search gave up after the given number of iterations without finding a repeat.
+    IterationLimit(usize),
+    /// A simulated search stopped after the given number of iterations because the frequency no
+    /// longer fit in an `i128`.
+    Overflow(usize),
+}
+
+/// Where the first repeated frequency was reached.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RepeatLocation {
+    /// The repeated frequency.
+    pub frequency: i128,
+    /// The number of deltas applied when the frequency was repeated, counting from 1. This
+    /// saturates at `u128::MAX` for repeats too far off to count.
+    pub iteration: u128,
+    /// The pass through the deltas during which the frequency was repeated, counting from 0.
+    pub pass: u128,
+    /// The position of the delta that produced the repeat among all the deltas, counting from 1.
+    pub line: usize,
+}
+
+/// Calibrates the device's frequency from a list of frequency deltas.
+///
+/// The deltas are parsed once and kept in memory, so that the list can be cycled through as many
+/// times as needed without going back to the input. Each delta is an `i64`, and frequencies are
+/// accumulated as `i128`s, so no pass through the deltas can overflow.
+#[derive(Debug)]
+pub struct FrequencyCalibrator {
+    deltas: Vec<i64>,
+}
+
+impl FrequencyCalibrator {
+    /// Returns a calibrator for the given deltas.
+    pub fn new(deltas: Vec<i64>) -> FrequencyCalibrator {
+        FrequencyCalibrator { deltas }
+    }
+
+    /// Parses a calibrator from puzzle input.
+    ///
+    /// Each delta is a signed integer (e.g. `+3` or `-2`). Deltas may be given one per line, or
+    /// several to a line separated by commas and/or whitespace (e.g. `+1, -2, +3`). Blank lines
+    /// are skipped, and everything from a `#` to the end of its line is a comment.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(calibrator)` if every delta is valid.
+    /// * `Err(message)` giving the line and column of the first invalid delta otherwise.
+    pub fn parse(input: &str) -> Result<FrequencyCalibrator, String> {
+        let mut deltas = Vec::new();
+        for (line_index, line) in input.lines().enumerate() {
+            let line_number = line_index + 1;
+            let content = match line.find('#') {
+                Some(comment) => &line[..comment],
+                None => line
+            };
+            let pieces: Vec<&str> = content.split(',').collect();
+            let mut offset = 0;
+            for (piece_index, piece) in pieces.iter().enumerate() {
+                let mut tokens = 0;
+                let mut token_start = None;
+                // Walk the piece a character at a time, so that each token's column is known.
+                for (i, c) in piece.char_indices().chain(Some((piece.len(), ' '))) {
+                    if c.is_whitespace() {
+                        if let Some(start) = token_start.take() {
+                            let token = &piece[start..i];
+                            let column = content[..offset + start].chars().count() + 1;
+                            let delta = token.parse::<i64>().map_err(|e| {
+                                format!("line {}, column {}: invalid frequency delta '{}': {}",
+                                        line_number, column, token, e)
+                            })?;
+                            deltas.push(delta);
+                            tokens += 1;
+                        }
+                    } else if token_start.is_none() {
+                        token_start = Some(i);
+                    }
+                }
+                // Only a trailing comma may be left without a delta after it.
+                if tokens == 0 && pieces.len() > 1 && piece_index < pieces.len() - 1 {
+                    let column = content[..offset + piece.len()].chars().count() + 1;
+                    return Err(format!("line {}, column {}: missing frequency delta before ','", line_number, column));
+                }
+                offset += piece.len() + 1;
+            }
+        }
+        Ok(FrequencyCalibrator::new(deltas))
+    }
+
+    /// Returns the deltas making up this calibrator.
+    pub fn deltas(&self) -> &[i64] {
+        &self.deltas
+    }
+
+    /// Returns an iterator over the frequency reached after each delta, cycling back to the first
+    /// delta after the last one. The iterator is empty if there are no deltas, and otherwise only
+    /// ends if the frequency would no longer fit in an `i128`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use day_one_2018::FrequencyCalibrator;
+    ///
+    /// let calibrator = FrequencyCalibrator::new(vec![1, -2, 3]);
+    /// let frequencies: Vec<i128> = calibrator.frequencies().take(4).collect();
+    /// assert_eq!(frequencies, vec![1, -1, 2, 3]);
+    /// ```
+    pub fn frequencies<'a>(&'a self) -> impl Iterator<Item = i128> + 'a {
+        self.deltas.iter().cycle().scan(0i128, |frequency, delta| {
+            *frequency = frequency.checked_add(i128::from(*delta))?;
+            Some(*frequency)
+        })
+    }
+
+    /// Returns the frequency reached after applying every delta once, starting from zero.
+    pub fn final_frequency(&self) -> i128 {
+        self.deltas.iter().map(|delta| i128::from(*delta)).sum()
+    }
+
+    /// Returns the first frequency reached twice while repeatedly cycling through the deltas,
+    /// counting the starting frequency of zero as already reached.
+    ///
+    /// Rather than simulating pass after pass, this works out the answer directly in
+    /// O(n log n). Every frequency reached is `k * drift + s[i]`, where `drift` is the net change
+    /// over one pass, `s[i]` is the sum of the first `i` deltas (for `i` in `0..n`) and `k` is the
+    /// number of completed passes. Two prefix sums can only ever meet if they are congruent
+    /// modulo the drift, and the lower of the two (relative to the direction of the drift) reaches
+    /// the higher one after `(s[j] - s[i]) / drift` passes. So grouping the prefix sums by residue,
+    /// sorting each group, and looking at neighbouring sums finds every candidate repeat. The first
+    /// pass is searched on its own beforehand, which also covers a drift of zero.
+    ///
+    /// # Returns
+    ///
+    /// * `RepeatOutcome::Repeat(frequency)` for the first repeated frequency.
+    /// * `RepeatOutcome::NoRepeat` if no frequency is ever repeated (see `never_repeats`).
+    pub fn first_repeat(&self) -> RepeatOutcome {
+        match self.first_repeat_location() {
+            Some(location) => RepeatOutcome::Repeat(location.frequency),
+            None => RepeatOutcome::NoRepeat
+        }
+    }
+
+    /// Returns where the first repeated frequency is reached, worked out the same way as
+    /// `first_repeat`, or `None` if no frequency is ever repeated.
+    pub fn first_repeat_location(&self) -> Option<RepeatLocation> {
+        let n = self.deltas.len();
+        if n == 0 {
+            return None;
+        }
+        let location = |iteration: u128, frequency: i128| RepeatLocation {
+            frequency,
+            iteration,
+            pass: (iteration - 1) / n as u128,
+            line: ((iteration - 1) % n as u128) as usize + 1,
+        };
+
+        // Search the first pass on its own. Any repeat found here happens no later than the end of
+        // the first pass, and so before any repeat that needs further passes.
+        let mut prefix_sums: Vec<i128> = Vec::with_capacity(n);
+        let mut seen = HashSet::new();
+        let mut sum: i128 = 0;
+        for delta in self.deltas.iter() {
+            prefix_sums.push(sum);
+            seen.insert(sum);
+            sum += i128::from(*delta);
+            if !seen.insert(sum) {
+                return Some(location(prefix_sums.len() as u128, sum));
+            }
+        }
+        // With no drift, the first pass ends back at zero and so has already returned.
+        let drift = sum;
+
+        // Group the (now known to be distinct) prefix sums by their residue modulo the drift,
+        // keeping track of the index each one came from.
+        let mut groups: HashMap<i128, Vec<(i128, usize)>> = HashMap::new();
+        for (index, prefix) in prefix_sums.iter().enumerate() {
+            groups.entry(prefix.rem_euclid(drift)).or_default().push((*prefix, index));
+        }
+
+        // Within a group, sort in the direction of the drift. Each sum then first meets the sum
+        // after it, after (next - sum) / drift passes, at which point it is at index `index` of
+        // that pass. The repeat happening at the earliest (pass, index) wins.
+        let direction = drift.signum();
+        let mut best: Option<(i128, usize, i128)> = None;
+        for group in groups.values_mut() {
+            group.sort_by_key(|&(prefix, _)| prefix * direction);
+            for pair in group.windows(2) {
+                let (prefix, index) = pair[0];
+                let next = pair[1].0;
+                let passes = (next - prefix) / drift;
+                let candidate = (passes, index, next);
+                if best.is_none_or(|b| (candidate.0, candidate.1) < (b.0, b.1)) {
+                    best = Some(candidate);
+                }
+            }
+        }
+        // Frequency `s[index]` of a pass is the one reached by the `index`th delta of that pass,
+        // or by the last delta of the previous pass when `index` is zero.
+        best.map(|(passes, index, frequency)| {
+            let iteration = (passes as u128).saturating_mul(n as u128).saturating_add(index as u128);
+            location(iteration, frequency)
+        })
+    }
+
+    /// Returns whether it can be shown that no frequency is ever reached twice.
+    ///
+    /// This is the case when there are no deltas, or when the first pass has no repeats of its
+    /// own, the drift over a pass is nonzero, and every prefix sum falls in a different residue
+    /// class modulo the drift. Each pass shifts every frequency by the drift, so frequencies that
+    /// start in different residue classes can never meet.
+    pub fn never_repeats(&self) -> bool {
+        if self.deltas.is_empty() {
+            return true;
+        }
+        let mut seen = HashSet::new();
+        let mut sum: i128 = 0;
+        seen.insert(sum);
+        for delta in self.deltas.iter().take(self.deltas.len() - 1) {
+            sum += i128::from(*delta);
+            if !seen.insert(sum) {
+                return false;
+            }
+        }
+        let drift = self.final_frequency();
+        if drift == 0 {
+            return false;
+        }
+        let residues: HashSet<i128> = seen.iter().map(|prefix| prefix.rem_euclid(drift)).collect();
+        residues.len() == seen.len()
+    }
+
+    /// Returns the first frequency reached twice by simulating pass after pass and remembering
+    /// every frequency seen. This is the straightforward approach `first_repeat` replaces, kept
+    /// as a fallback for checking its results.
+    ///
+    /// Inputs that `never_repeats` proves will never repeat return `RepeatOutcome::NoRepeat`
+    /// straight away. Otherwise the simulation runs until it finds a repeat, or until it has
+    /// applied `max_iterations` deltas if a limit is given.
+    pub fn first_repeat_simulated(&self, max_iterations: Option<usize>) -> RepeatOutcome {
+        if self.never_repeats() {
+            return RepeatOutcome::NoRepeat;
+        }
+        let mut seen = HashSet::new();
+        seen.insert(0);
+        let mut frequencies = self.frequencies();
+        for iteration in 0.. {
+            if max_iterations.is_some_and(|max| iteration >= max) {
+                return RepeatOutcome::IterationLimit(iteration);
+            }
+            match frequencies.next() {
+                Some(frequency) if !seen.insert(frequency) => return RepeatOutcome::Repeat(frequency),
+                Some(_) => {},
+                None => return RepeatOutcome::Overflow(iteration)
+            }
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrequencyCalibrator;
+    use super::RepeatOutcome::IterationLimit;
+    use super::RepeatOutcome::NoRepeat;
+    use super::RepeatOutcome::Repeat;
+
+    fn calibrator(deltas: &[i64]) -> FrequencyCalibrator {
+        FrequencyCalibrator::new(deltas.to_vec())
+    }
+
+    #[test]
+    fn parses_signed_deltas() {
+        let calibrator = FrequencyCalibrator::parse("+1\n-2\n+3\n+1\n").unwrap();
+        assert_eq!(calibrator.deltas(), &[1, -2, 3, 1]);
+    }
+
+    #[test]
+    fn parse_reports_bad_line() {
+        let err = FrequencyCalibrator::parse("+1\nfoo\n").unwrap_err();
+        assert!(err.starts_with("line 2, column 1:"), "{}", err);
+    }
+
+    #[test]
+    fn parses_separated_deltas() {
+        let input = "# Calibration for the tests\n+1, -2, +3\n\n  +4 -5\t+6,\n+7 # trailing comment\n";
+        let calibrator = FrequencyCalibrator::parse(input).unwrap();
+        assert_eq!(calibrator.deltas(), &[1, -2, 3, 4, -5, 6, 7]);
+    }
+
+    #[test]
+    fn parse_points_to_bad_token() {
+        let err = FrequencyCalibrator::parse("+1, -2\n+3, 4x, +5\n").unwrap_err();
+        assert!(err.starts_with("line 2, column 5: invalid frequency delta '4x'"), "{}", err);
+        let err = FrequencyCalibrator::parse("+1,, -2\n").unwrap_err();
+        assert!(err.starts_with("line 1, column 4: missing frequency delta"), "{}", err);
+        let err = FrequencyCalibrator::parse("+1\n  +9223372036854775808\n").unwrap_err();
+        assert!(err.starts_with("line 2, column 3: invalid frequency delta '+9223372036854775808'"), "{}", err);
+    }
+
+    #[test]
+    fn accumulates_without_overflow() {
+        let max = i64::MAX;
+        let calibrator = calibrator(&[max, max, -max, -max, 1]);
+        assert_eq!(calibrator.final_frequency(), 1);
+        assert_eq!(calibrator.frequencies().nth(1), Some(2 * i128::from(max)));
+        assert_eq!(calibrator.first_repeat(), calibrator.first_repeat_simulated(None));
+    }
+
+    #[test]
+    fn final_frequency() {
+        assert_eq!(calibrator(&[1, -2, 3, 1]).final_frequency(), 3);
+        assert_eq!(calibrator(&[1, 1, 1]).final_frequency(), 3);
+        assert_eq!(calibrator(&[1, 1, -2]).final_frequency(), 0);
+        assert_eq!(calibrator(&[-1, -2, -3]).final_frequency(), -6);
+    }
+
+    #[test]
+    fn first_repeat() {
+        assert_eq!(calibrator(&[1, -2, 3, 1]).first_repeat(), Repeat(2));
+        assert_eq!(calibrator(&[1, -1]).first_repeat(), Repeat(0));
+        assert_eq!(calibrator(&[3, 3, 4, -2, -4]).first_repeat(), Repeat(10));
+        assert_eq!(calibrator(&[-6, 3, 8, 5, -6]).first_repeat(), Repeat(5));
+        assert_eq!(calibrator(&[7, 7, -2, -7, -4]).first_repeat(), Repeat(14));
+    }
+
+    #[test]
+    fn first_repeat_within_first_pass() {
+        assert_eq!(calibrator(&[2, 3, -3, 4]).first_repeat(), Repeat(2));
+        assert_eq!(calibrator(&[5, -5, 7]).first_repeat(), Repeat(0));
+        assert_eq!(calibrator(&[1, 2, -3]).first_repeat(), Repeat(0));
+    }
+
+    #[test]
+    fn first_repeat_with_negative_drift() {
+        assert_eq!(calibrator(&[-1, 2, -3]).first_repeat(), calibrator(&[-1, 2, -3]).first_repeat_simulated(None));
+        assert_eq!(calibrator(&[-6, 3, 8, 5, -12]).first_repeat(), Repeat(-6));
+    }
+
+    #[test]
+    fn never_repeats() {
+        assert_eq!(calibrator(&[]).first_repeat(), NoRepeat);
+        assert_eq!(calibrator(&[1]).first_repeat(), NoRepeat);
+        assert_eq!(calibrator(&[1, 1, 1]).first_repeat(), NoRepeat);
+        assert_eq!(calibrator(&[-4, 1]).first_repeat(), NoRepeat);
+    }
+
+    #[test]
+    fn matches_simulation() {
+        // A small linear congruential generator, so the deltas are varied but reproducible.
+        let mut state: u32 = 12345;
+        let mut next = || {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            ((state >> 16) % 41) as i64 - 20
+        };
+        for length in 1..40 {
+            let deltas: Vec<i64> = (0..length).map(|_| next()).collect();
+            let calibrator = calibrator(&deltas);
+            let expected = calibrator.first_repeat();
+            assert_eq!(calibrator.never_repeats(), expected == NoRepeat, "{:?}", deltas);
+            if expected == NoRepeat {
+                // Without the proof the simulation would never finish, so only check that it finds
+                // nothing for a good while.
+                let mut seen = std::collections::HashSet::new();
+                seen.insert(0);
+                assert!(calibrator.frequencies().take(100_000).all(|f| seen.insert(f)), "{:?}", deltas);
+            }
+            assert_eq!(calibrator.first_repeat_simulated(None), expected, "{:?}", deltas);
+        }
+    }
+
+    #[test]
+    fn proves_non_termination() {
+        assert!(calibrator(&[]).never_repeats());
+        assert!(calibrator(&[3, 1]).never_repeats());
+        assert!(!calibrator(&[1, -1]).never_repeats());
+        assert!(calibrator(&[2, 2]).never_repeats());
+        assert!(!calibrator(&[2, -1]).never_repeats());
+        assert_eq!(calibrator(&[3, 1]).first_repeat_simulated(None), NoRepeat);
+    }
+
+    #[test]
+    fn simulation_gives_up_at_limit() {
+        // Repeats at 10, but only after 7 iterations.
+        let calibrator = calibrator(&[3, 3, 4, -2, -4]);
+        assert_eq!(calibrator.first_repeat_simulated(Some(6)), IterationLimit(6));
+        assert_eq!(calibrator.first_repeat_simulated(Some(7)), Repeat(10));
+    }
+}
@@ -0,0 +1,54 @@
+extern crate solution;
+
+mod calibrator;
+mod trace;
+
+use solution::Solution;
+
+pub use calibrator::FrequencyCalibrator;
+pub use calibrator::RepeatLocation;
+pub use calibrator::RepeatOutcome;
+pub use trace::CalibrationStats;
+pub use trace::TraceStep;
+
+/// Solution to day one: calibrating the device's frequency from a list of deltas.
+pub struct DayOne {
+    calibrator: FrequencyCalibrator,
+}
+
+impl DayOne {
+    pub fn new() -> DayOne {
+        DayOne { calibrator: FrequencyCalibrator::new(Vec::new()) }
+    }
+
+    /// Returns the calibrator built from the parsed input.
+    pub fn calibrator(&self) -> &FrequencyCalibrator {
+        &self.calibrator
+    }
+}
+
+impl Default for DayOne {
+    fn default() -> DayOne {
+        DayOne::new()
+    }
+}
+
+impl Solution for DayOne {
+    fn parse(&mut self, input: &str) -> Result<(), String> {
+        self.calibrator = FrequencyCalibrator::parse(input)?;
+        Ok(())
+    }
+
+    fn part_one(&mut self) -> String {
+        self.calibrator.final_frequency().to_string()
+    }
+
+    fn part_two(&mut self) -> String {
+        match self.calibrator.first_repeat() {
+            RepeatOutcome::Repeat(frequency) => frequency.to_string(),
+            RepeatOutcome::NoRepeat => String::from("never repeats"),
+            RepeatOutcome::IterationLimit(iterations) => format!("no repeat within {} iterations", iterations),
+            RepeatOutcome::Overflow(iterations) => format!("overflowed after {} iterations", iterations)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+extern crate day_one_2018;
+extern crate input;
+
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+use std::process;
+
+use day_one_2018::FrequencyCalibrator;
+use day_one_2018::RepeatOutcome;
+
+fn main() {
+    // Usage: day-one-2018 <input> [--max-iterations <n>] [--trace <csv>] [--stats]
+    // Giving a maximum number of iterations searches for the first repeat by simulation, stopping
+    // after that many deltas have been applied. --trace writes every step of calibration to a CSV
+    // file, and --stats prints summary statistics for the run, both stopping after the maximum number
+    // of iterations if one is given.
+    let args: Vec<String> = env::args().collect();
+    let mut path = None;
+    let mut max_iterations = None;
+    let mut trace_path = None;
+    let mut stats = false;
+    let mut args_iter = args.iter().skip(1);
+    while let Some(arg) = args_iter.next() {
+        if arg == "--max-iterations" {
+            let max = args_iter.next().expect("Missing maximum number of iterations");
+            max_iterations = Some(max.parse::<usize>().expect("Invalid maximum number of iterations"));
+        } else if arg == "--trace" {
+            trace_path = Some(args_iter.next().expect("Missing trace file"));
+        } else if arg == "--stats" {
+            stats = true;
+        } else {
+            assert!(path.is_none(), "Incorrect number of args");
+            path = Some(arg);
+        }
+    }
+    let input = input::load_file(path.expect("Incorrect number of args")).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let calibrator = FrequencyCalibrator::parse(&input).unwrap();
+    println!("Result after 1 iteration: {}", calibrator.final_frequency());
+    let outcome = match max_iterations {
+        Some(_) => calibrator.first_repeat_simulated(max_iterations),
+        None => calibrator.first_repeat()
+    };
+    match outcome {
+        RepeatOutcome::Repeat(frequency) => println!("First repeated frequency: {}", frequency),
+        RepeatOutcome::NoRepeat => println!("No frequency is ever repeated"),
+        RepeatOutcome::IterationLimit(iterations) => {
+            println!("No repeated frequency found within {} iterations", iterations)
+        },
+        RepeatOutcome::Overflow(iterations) => {
+            println!("Frequency overflowed after {} iterations without repeating", iterations)
+        }
+    }
+
+    if let Some(trace_path) = trace_path {
+        let mut writer = BufWriter::new(File::create(trace_path).expect("Unable to create trace file"));
+        calibrator.write_trace_csv(&mut writer, max_iterations.map(|max| max as u64))
+            .expect("Unable to write trace file");
+    }
+    if stats {
+        let stats = calibrator.stats(max_iterations.map(|max| max as u64));
+        println!("Drift per pass: {}", stats.drift);
+        println!("Frequency range: {} to {}", stats.min_frequency, stats.max_frequency);
+        match stats.first_repeat {
+            Some(location) => println!("First repeat reached on pass {}, line {} (iteration {})",
+                                       location.pass, location.line, location.iteration),
+            None => match max_iterations {
+                Some(max) => println!("First repeat not reached within {} iterations", max),
+                None => println!("First repeat never reached")
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::io;
+use std::io::Write;
+
+use calibrator::FrequencyCalibrator;
+use calibrator::RepeatLocation;
+
+/// A single step of calibration: applying one delta to the running frequency.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TraceStep {
+    /// The number of deltas applied so far, including this one, counting from 1.
+    pub iteration: u64,
+    /// The pass through the deltas this step belongs to, counting from 0.
+    pub pass: u64,
+    /// The position of the applied delta, counting from 1.
+    pub line: usize,
+    /// The applied delta.
+    pub delta: i64,
+    /// The frequency after applying the delta.
+    pub frequency: i128,
+}
+
+/// Summary statistics for a calibration run, which lasts until the first repeated frequency is
+/// reached, or for a single pass if no frequency is ever repeated, unless it's cut short by a
+/// maximum number of iterations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CalibrationStats {
+    /// The net change in frequency over one pass through the deltas.
+    pub drift: i128,
+    /// The lowest frequency reached during the run, including the starting frequency of zero.
+    /// This saturates at `i128::MIN`.
+    pub min_frequency: i128,
+    /// The highest frequency reached during the run, including the starting frequency of zero.
+    /// This saturates at `i128::MAX`.
+    pub max_frequency: i128,
+    /// Where the first repeated frequency was reached, if it was during the run.
+    pub first_repeat: Option<RepeatLocation>,
+}
+
+impl FrequencyCalibrator {
+    /// Returns an iterator over every step of calibration, cycling through the deltas. The
+    /// iterator is empty if there are no deltas, and otherwise only ends if the frequency would no
+    /// longer fit in an `i128`.
+    pub fn trace<'a>(&'a self) -> impl Iterator<Item = TraceStep> + 'a {
+        let n = self.deltas().len() as u64;
+        self.deltas().iter().cycle().zip(1..).scan(0i128, move |frequency, (delta, iteration)| {
+            *frequency = frequency.checked_add(i128::from(*delta))?;
+            Some(TraceStep {
+                iteration,
+                pass: (iteration - 1) / n,
+                line: ((iteration - 1) % n) as usize + 1,
+                delta: *delta,
+                frequency: *frequency,
+            })
+        })
+    }
+
+    /// Returns the summary statistics for calibrating with these deltas, applying at most
+    /// `max_iterations` of them if given.
+    ///
+    /// The run can span a great many passes, so rather than replaying it, the extremes are taken
+    /// from the first and last full passes (every other pass lies between them, shifted by the
+    /// drift) and from the partial pass the run ends in.
+    pub fn stats(&self, max_iterations: Option<u64>) -> CalibrationStats {
+        let deltas = self.deltas();
+        let drift = self.final_frequency();
+        let mut first_repeat = self.first_repeat_location();
+        let (mut full_passes, mut partial_lines) = match first_repeat {
+            Some(location) => (location.pass, location.line),
+            None => (1, 0)
+        };
+        let steps = first_repeat.map_or(deltas.len() as u128, |location| location.iteration);
+        if let Some(max) = max_iterations.map(u128::from).filter(|&max| max < steps) {
+            // The run is cut off before it would have ended, so it never reaches a repeat.
+            first_repeat = None;
+            full_passes = max / deltas.len() as u128;
+            partial_lines = (max % deltas.len() as u128) as usize;
+        }
+
+        let mut min_frequency = 0;
+        let mut max_frequency = 0;
+        let mut include = |frequency: i128| {
+            min_frequency = min_frequency.min(frequency);
+            max_frequency = max_frequency.max(frequency);
+        };
+        // The number of passes fits in an i128, since it is at most the largest difference between
+        // two prefix sums.
+        let shift = |passes: u128| (passes as i128).saturating_mul(drift);
+        let mut sum: i128 = 0;
+        for (index, delta) in deltas.iter().enumerate() {
+            sum += i128::from(*delta);
+            if full_passes > 0 {
+                include(sum);
+                include(sum.saturating_add(shift(full_passes - 1)));
+            }
+            if index < partial_lines {
+                include(sum.saturating_add(shift(full_passes)));
+            }
+        }
+
+        CalibrationStats { drift, min_frequency, max_frequency, first_repeat }
+    }
+
+    /// Writes the steps of a calibration run to `writer` as CSV, with a header row followed by
+    /// one `iteration,pass,line,delta,frequency` row per step.
+    ///
+    /// The run lasts until the first repeated frequency is reached, or for a single pass if no
+    /// frequency is ever repeated. If `max_iterations` is given, at most that many steps are
+    /// written.
+    pub fn write_trace_csv<W: Write>(&self, writer: &mut W, max_iterations: Option<u64>) -> io::Result<()> {
+        let steps = match self.first_repeat_location() {
+            Some(location) => location.iteration,
+            None => self.deltas().len() as u128
+        };
+        let steps = max_iterations.map_or(steps, |max| steps.min(u128::from(max)));
+        writeln!(writer, "iteration,pass,line,delta,frequency")?;
+        for step in self.trace().take(steps.min(usize::MAX as u128) as usize) {
+            writeln!(writer, "{},{},{},{},{}", step.iteration, step.pass, step.line, step.delta, step.frequency)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calibrator::FrequencyCalibrator;
+    use calibrator::RepeatLocation;
+
+    #[test]
+    fn stats_match_trace() {
+        for deltas in [vec![1, -2, 3, 1], vec![3, 3, 4, -2, -4], vec![-6, 3, 8, 5, -6], vec![7, 7, -2, -7, -4],
+                       vec![-6, 3, 8, 5, -12], vec![3, 1], vec![5, -5, 7]].iter() {
+            let calibrator = FrequencyCalibrator::new(deltas.clone());
+            let run_length = calibrator.stats(None).first_repeat.map_or(deltas.len() as u64, |location| {
+                location.iteration as u64
+            });
+            for max_iterations in [None, Some(0), Some(1), Some(run_length - 1), Some(run_length)].iter() {
+                let stats = calibrator.stats(*max_iterations);
+                let steps = max_iterations.unwrap_or(run_length) as usize;
+                let trace: Vec<i128> = calibrator.trace().take(steps).map(|step| step.frequency).collect();
+                let context = (deltas, max_iterations);
+                assert_eq!(stats.min_frequency, trace.iter().cloned().min().unwrap_or(0).min(0), "{:?}", context);
+                assert_eq!(stats.max_frequency, trace.iter().cloned().max().unwrap_or(0).max(0), "{:?}", context);
+                let reached = calibrator.first_repeat_location().filter(|location| location.iteration <= steps as u128);
+                assert_eq!(stats.first_repeat, reached, "{:?}", context);
+                if let Some(location) = stats.first_repeat {
+                    assert_eq!(*trace.last().unwrap(), location.frequency, "{:?}", context);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn locates_first_repeat() {
+        let calibrator = FrequencyCalibrator::new(vec![3, 3, 4, -2, -4]);
+        assert_eq!(calibrator.stats(None).drift, 4);
+        // A run cut off before the repeat doesn't reach it.
+        assert_eq!(calibrator.stats(Some(6)).first_repeat, None);
+        assert_eq!(calibrator.stats(Some(6)).max_frequency, 10);
+        assert_eq!(calibrator.first_repeat_location(), Some(RepeatLocation {
+            frequency: 10,
+            iteration: 7,
+            pass: 1,
+            line: 2,
+        }));
+    }
+
+    #[test]
+    fn writes_csv() {
+        let calibrator = FrequencyCalibrator::new(vec![1, -2, 3, 1]);
+        let mut csv = Vec::new();
+        calibrator.write_trace_csv(&mut csv, None).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "iteration,pass,line,delta,frequency\n\
+                                                     1,0,1,1,1\n2,0,2,-2,-1\n3,0,3,3,2\n4,0,4,1,3\n\
+                                                     5,1,1,1,4\n6,1,2,-2,2\n");
+    }
+}
@@ -0,0 +1,73 @@
+extern crate bench;
+extern crate day_three_2018;
+extern crate input;
+extern crate solution;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use day_three_2018::Claim;
+use day_three_2018::DayThree;
+use day_three_2018::FabricGrid;
+use input::InputCache;
+use solution::Solution;
+
+/// The original fabric map, which recorded a claim ID and count per square inch in a hash map.
+fn hash_map_fabric(claims: &[Claim]) -> (u128, Vec<usize>) {
+    let mut fabric_map: HashMap<(usize, usize), (String, i32)> = HashMap::new();
+    let mut overlaps = 0;
+    let mut perfect_claims: HashSet<String> = HashSet::new();
+    for claim in claims.iter() {
+        let id = claim.id.to_string();
+        perfect_claims.insert(id.clone());
+        for row in claim.y..claim.y + claim.height {
+            for col in claim.x..claim.x + claim.width {
+                let entry = fabric_map.entry((row, col)).or_insert((id.clone(), 0));
+                if entry.1 == 0 {
+                    entry.1 = 1;
+                } else {
+                    if entry.1 == 1 {
+                        overlaps += 1;
+                    }
+                    perfect_claims.remove(&entry.0);
+                    perfect_claims.remove(&id);
+                    entry.0 = id.clone();
+                    entry.1 = 2;
+                }
+            }
+        }
+    }
+    let mut perfect_claims: Vec<usize> = perfect_claims.iter().map(|id| id.parse().unwrap()).collect();
+    perfect_claims.sort();
+    (overlaps as u128, perfect_claims)
+}
+
+/// The dense grid fabric map, as `DayThree` uses it.
+fn grid_fabric(claims: &[Claim]) -> (u128, Vec<usize>) {
+    let grid = FabricGrid::new(claims).unwrap();
+    let mut perfect_claims: Vec<usize> = claims.iter()
+        .filter(|claim| grid.is_alone(claim))
+        .map(|claim| claim.id)
+        .collect();
+    perfect_claims.sort();
+    (grid.overlap_area() as u128, perfect_claims)
+}
+
+fn main() {
+    let cache = InputCache::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../inputs"));
+    let input = match cache.load(2018, 3, input::DEFAULT_NAME) {
+        Ok(input) => input,
+        Err(e) => {
+            println!("Skipping fabric map benchmark: {}", e);
+            return;
+        }
+    };
+    let mut day = DayThree::new();
+    day.parse(&input).unwrap();
+    let claims = day.claims().to_vec();
+
+    let (hash_map_time, hash_map_result) = bench::fastest(|| hash_map_fabric(&claims));
+    let (grid_time, grid_result) = bench::fastest(|| grid_fabric(&claims));
+    assert_eq!(hash_map_result, grid_result, "Fabric maps disagree");
+    println!("{} claims: hash map {:?}, dense grid {:?}", claims.len(), hash_map_time, grid_time);
+}
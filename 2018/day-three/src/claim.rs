@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+/// A rectangle as an `(x, y, width, height)` tuple, where `(x, y)` is its top left corner.
+pub type Rectangle = (usize, usize, usize, usize);
+
+/// An elf's claim to a rectangle of fabric, e.g. `#123 @ 3,2: 5x4`.
+///
+/// The claim covers the square inches from `x` to `x + width - 1` (counting from the left edge of
+/// the fabric) and from `y` to `y + height - 1` (counting from the top edge).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Claim {
+    pub id: usize,
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Claim {
+    /// Returns the number of square inches covered by this claim.
+    pub fn area(&self) -> u128 {
+        self.width as u128 * self.height as u128
+    }
+
+    /// Returns the claimed rectangle.
+    pub fn rectangle(&self) -> Rectangle {
+        (self.x, self.y, self.width, self.height)
+    }
+
+    /// Returns whether this claim and `other` share at least one square inch.
+    pub fn intersects(&self, other: &Claim) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the rectangle covered by both this claim and `other`, or `None` if they don't
+    /// share any square inches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use day_three_2018::Claim;
+    ///
+    /// let first: Claim = "#1 @ 1,3: 4x4".parse().unwrap();
+    /// let second: Claim = "#2 @ 3,1: 4x4".parse().unwrap();
+    /// let third: Claim = "#3 @ 5,5: 2x2".parse().unwrap();
+    /// assert_eq!(first.intersection(&second), Some((3, 3, 2, 2)));
+    /// assert_eq!(first.intersection(&third), None);
+    /// ```
+    pub fn intersection(&self, other: &Claim) -> Option<Rectangle> {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        if left < right && top < bottom {
+            Some((left, top, right - left, bottom - top))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether this claim covers the square inch at `(x, y)`.
+    pub fn contains_point(&self, x: usize, y: usize) -> bool {
+        (self.x..self.x + self.width).contains(&x) && (self.y..self.y + self.height).contains(&y)
+    }
+}
+
+/// Parses a non-negative decimal number made of digits only.
+fn parse_number(s: &str) -> Option<usize> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+impl FromStr for Claim {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Claim, String> {
+        let invalid = || format!("invalid claim '{}'", s);
+        let rest = s.strip_prefix('#').ok_or_else(invalid)?;
+        let (id, rest) = rest.split_once(" @ ").ok_or_else(invalid)?;
+        let (position, size) = rest.split_once(": ").ok_or_else(invalid)?;
+        let (x, y) = position.split_once(',').ok_or_else(invalid)?;
+        let (width, height) = size.split_once('x').ok_or_else(invalid)?;
+        let number = |n: &str| parse_number(n).ok_or_else(invalid);
+        let claim = Claim {
+            id: number(id)?,
+            x: number(x)?,
+            y: number(y)?,
+            width: number(width)?,
+            height: number(height)?,
+        };
+        // The far edges must be coordinates too.
+        if claim.x.checked_add(claim.width).is_none() || claim.y.checked_add(claim.height).is_none() {
+            return Err(invalid());
+        }
+        Ok(claim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::claim;
+    use super::Claim;
+
+    #[test]
+    fn parses_claims() {
+        assert_eq!(claim("#123 @ 3,2: 5x4"), Claim { id: 123, x: 3, y: 2, width: 5, height: 4 });
+        for invalid in ["", "#1 @ 1,3 4x4", "1 @ 1,3: 4x4", "#1 @ 1,3: 4x", "#1 @ -1,3: 4x4", "#1 @ 1,3: 4x4x4",
+                        "#1 @ 1,+3: 4x4", "#x @ 1,3: 4x4", "#1 @ 18446744073709551615,3: 1x4"].iter() {
+            assert_eq!(invalid.parse::<Claim>(), Err(format!("invalid claim '{}'", invalid)));
+        }
+    }
+
+    #[test]
+    fn measures_area() {
+        assert_eq!(claim("#1 @ 1,3: 4x4").area(), 16);
+        assert_eq!(claim("#1 @ 1,3: 0x4").area(), 0);
+        assert_eq!(claim("#1 @ 0,0: 5000000000x5000000000").area(), 25_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn intersects_claims() {
+        let first = claim("#1 @ 1,3: 4x4");
+        assert!(first.intersects(&claim("#2 @ 3,1: 4x4")));
+        assert!(first.intersects(&first));
+        assert_eq!(first.intersection(&first), Some(first.rectangle()));
+        // Claims that only touch along an edge don't share any square inches.
+        assert!(!first.intersects(&claim("#3 @ 5,3: 2x2")));
+        assert!(!first.intersects(&claim("#4 @ 1,7: 4x1")));
+        // A claim inside another intersects it in the inner claim.
+        assert_eq!(first.intersection(&claim("#5 @ 2,4: 1x2")), Some((2, 4, 1, 2)));
+        assert!(!first.intersects(&claim("#6 @ 2,4: 0x2")));
+    }
+
+    #[test]
+    fn contains_points() {
+        let claim = claim("#1 @ 1,3: 4x4");
+        assert!(claim.contains_point(1, 3));
+        assert!(claim.contains_point(4, 6));
+        assert!(!claim.contains_point(5, 6));
+        assert!(!claim.contains_point(4, 7));
+        assert!(!claim.contains_point(0, 3));
+    }
+}
@@ -0,0 +1,125 @@
+use claim::Claim;
+
+/// The most square inches a grid will map, which keeps it to a few hundred megabytes. Larger
+/// fabrics are left to the sweeps in `sweep`.
+pub const MAX_AREA: usize = 1 << 27;
+
+/// The number of claims covering each square inch of fabric, stored densely row by row.
+///
+/// The grid only extends as far right and down as the claims do, so it takes memory proportional
+/// to the fabric's area rather than to the number of claims.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FabricGrid {
+    width: usize,
+    height: usize,
+    /// The claim count for `(x, y)` is at index `y * width + x`. Counts saturate at `u16::MAX`.
+    counts: Vec<u16>,
+}
+
+impl FabricGrid {
+    /// Returns the width and height of the grid needed to map `claims`, or `None` if a claim
+    /// extends past the largest coordinate or the grid would cover more than `MAX_AREA` square
+    /// inches.
+    pub fn size_for(claims: &[Claim]) -> Option<(usize, usize)> {
+        let mut size = (0, 0);
+        for claim in claims.iter() {
+            size.0 = size.0.max(claim.x.checked_add(claim.width)?);
+            size.1 = size.1.max(claim.y.checked_add(claim.height)?);
+        }
+        match size.0.checked_mul(size.1) {
+            Some(area) if area <= MAX_AREA => Some(size),
+            _ => None
+        }
+    }
+
+    /// Counts the claims covering each square inch of fabric, or returns an error if the grid
+    /// would be too large (see `size_for`).
+    pub fn new(claims: &[Claim]) -> Result<FabricGrid, String> {
+        let (width, height) = FabricGrid::size_for(claims)
+            .ok_or_else(|| format!("fabric is larger than the {} square inches a grid can map", MAX_AREA))?;
+        let mut counts = vec![0u16; width * height];
+        for claim in claims.iter() {
+            for row in claim.y..claim.y + claim.height {
+                let start = row * width + claim.x;
+                for count in counts[start..start + claim.width].iter_mut() {
+                    *count = count.saturating_add(1);
+                }
+            }
+        }
+        Ok(FabricGrid { width, height, counts })
+    }
+
+    /// Returns the width and height of the grid.
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Returns the number of claims covering the square inch at `(x, y)`.
+    pub fn count(&self, x: usize, y: usize) -> u16 {
+        if x < self.width && y < self.height {
+            self.counts[y * self.width + x]
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of square inches covered by at least `times` claims.
+    pub fn covered_area(&self, times: u16) -> usize {
+        self.counts.iter().filter(|&&count| count >= times).count()
+    }
+
+    /// Returns the number of square inches covered by two or more claims.
+    pub fn overlap_area(&self) -> usize {
+        self.covered_area(2)
+    }
+
+    /// Returns whether no other claim covers any square inch of `claim`, which must be one of
+    /// the claims the grid was built from.
+    pub fn is_alone(&self, claim: &Claim) -> bool {
+        (claim.y..claim.y + claim.height).all(|row| {
+            let start = row * self.width + claim.x;
+            self.counts[start..start + claim.width].iter().all(|&count| count == 1)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::Claim;
+    use testing::claims;
+    use super::FabricGrid;
+
+    #[test]
+    fn counts_claims() {
+        let claims = claims(&["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4", "#3 @ 5,5: 2x2"]);
+        let grid = FabricGrid::new(&claims).unwrap();
+        assert_eq!(grid.size(), (7, 7));
+        assert_eq!(grid.count(0, 0), 0);
+        assert_eq!(grid.count(1, 3), 1);
+        assert_eq!(grid.count(4, 4), 2);
+        assert_eq!(grid.count(100, 100), 0);
+        assert_eq!(grid.overlap_area(), 4);
+        assert_eq!(grid.covered_area(1), 16 + 16 + 4 - 4);
+        assert_eq!(grid.covered_area(3), 0);
+        let alone: Vec<usize> = claims.iter().filter(|claim| grid.is_alone(claim)).map(|claim| claim.id).collect();
+        assert_eq!(alone, vec![3]);
+    }
+
+    #[test]
+    fn handles_no_claims() {
+        let grid = FabricGrid::new(&[]).unwrap();
+        assert_eq!(grid.size(), (0, 0));
+        assert_eq!(grid.overlap_area(), 0);
+    }
+
+    #[test]
+    fn refuses_huge_fabric() {
+        assert_eq!(FabricGrid::size_for(&claims(&["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4"])), Some((7, 7)));
+        assert_eq!(FabricGrid::size_for(&[]), Some((0, 0)));
+        assert_eq!(FabricGrid::size_for(&claims(&["#1 @ 0,0: 200000x200000"])), None);
+        assert_eq!(FabricGrid::size_for(&claims(&["#1 @ 0,0: 5000000000x5000000000"])), None);
+        let past_the_edge = Claim { id: 1, x: usize::MAX, y: 0, width: 1, height: 1 };
+        assert_eq!(FabricGrid::size_for(&[past_the_edge]), None);
+        assert!(FabricGrid::new(&[past_the_edge]).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use claim::Claim;
+use claim::Rectangle;
+
+/// The most children a node of the index can have.
+const NODE_CAPACITY: usize = 8;
+
+/// A bounding box as `(left, top, right, bottom)`, where the right and bottom edges are exclusive.
+type Bounds = (usize, usize, usize, usize);
+
+fn claim_bounds(claim: &Claim) -> Bounds {
+    (claim.x, claim.y, claim.x + claim.width, claim.y + claim.height)
+}
+
+fn union(a: Bounds, b: Bounds) -> Bounds {
+    (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3))
+}
+
+fn has_area(bounds: Bounds) -> bool {
+    bounds.0 < bounds.2 && bounds.1 < bounds.3
+}
+
+/// Returns whether the boxes share any area. Boxes that only touch along an edge don't, and
+/// neither do boxes without any area.
+fn overlaps(a: Bounds, b: Bounds) -> bool {
+    has_area(a) && has_area(b) && a.0 < b.2 && b.0 < a.2 && a.1 < b.3 && b.1 < a.3
+}
+
+fn contains(bounds: Bounds, x: usize, y: usize) -> bool {
+    bounds.0 <= x && x < bounds.2 && bounds.1 <= y && y < bounds.3
+}
+
+struct Node {
+    bounds: Bounds,
+    /// Whether `children` are indices of claims rather than of other nodes.
+    leaf: bool,
+    children: Vec<usize>,
+}
+
+/// An R-tree over claims, for quickly finding the claims covering a square inch or intersecting a
+/// rectangle.
+///
+/// The tree is packed once when it's built (using sort-tile-recursive packing), and can't be
+/// changed afterwards.
+///
+/// # Example
+///
+/// ```
+/// use day_three_2018::Claim;
+/// use day_three_2018::ClaimIndex;
+///
+/// let claims: Vec<Claim> = ["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4", "#3 @ 5,5: 2x2"].iter()
+///     .map(|claim| claim.parse().unwrap())
+///     .collect();
+/// let index = ClaimIndex::new(&claims);
+/// let ids = |claims: Vec<&Claim>| claims.iter().map(|claim| claim.id).collect::<Vec<usize>>();
+/// assert_eq!(ids(index.claims_at(4, 4)), vec![1, 2]);
+/// assert_eq!(ids(index.claims_at(0, 0)), vec![]);
+/// assert_eq!(ids(index.claims_intersecting((4, 4, 2, 2))), vec![1, 2, 3]);
+/// ```
+pub struct ClaimIndex {
+    claims: Vec<Claim>,
+    nodes: Vec<Node>,
+}
+
+impl ClaimIndex {
+    /// Builds an index over `claims`.
+    pub fn new(claims: &[Claim]) -> ClaimIndex {
+        let mut index = ClaimIndex { claims: claims.to_vec(), nodes: Vec::new() };
+        let entries: Vec<(Bounds, usize)> = index.claims.iter().map(claim_bounds).zip(0..).collect();
+        let mut level = index.pack(entries, true);
+        while level.len() > 1 {
+            let entries = level.iter().map(|&node| (index.nodes[node].bounds, node)).collect();
+            level = index.pack(entries, false);
+        }
+        index
+    }
+
+    /// Groups the entries into nodes of nearby entries, adds the nodes to the tree and returns
+    /// their indices.
+    fn pack(&mut self, mut entries: Vec<(Bounds, usize)>, leaf: bool) -> Vec<usize> {
+        // Cut the entries into vertical slices by the x coordinate of their centres, then cut each
+        // slice into nodes by the y coordinate of their centres.
+        let node_count = entries.len().div_ceil(NODE_CAPACITY);
+        let slice_count = (node_count as f64).sqrt().ceil() as usize;
+        let slice_len = slice_count * NODE_CAPACITY;
+        entries.sort_by_key(|&(bounds, _)| bounds.0 + bounds.2);
+        let mut level = Vec::with_capacity(node_count);
+        for slice in entries.chunks_mut(slice_len.max(1)) {
+            slice.sort_by_key(|&(bounds, _)| bounds.1 + bounds.3);
+            for group in slice.chunks(NODE_CAPACITY) {
+                let bounds = group.iter().skip(1).fold(group[0].0, |bounds, entry| union(bounds, entry.0));
+                let children = group.iter().map(|&(_, child)| child).collect();
+                self.nodes.push(Node { bounds, leaf, children });
+                level.push(self.nodes.len() - 1);
+            }
+        }
+        level
+    }
+
+    /// Returns the indexed claims.
+    pub fn claims(&self) -> &[Claim] {
+        &self.claims
+    }
+
+    /// Adds to `found` the indices of the claims under `node` whose bounds satisfy `matches`,
+    /// skipping any node whose bounds don't satisfy it.
+    fn search<F: Fn(Bounds) -> bool>(&self, node: usize, matches: &F, found: &mut Vec<usize>) {
+        let node = &self.nodes[node];
+        if !matches(node.bounds) {
+            return;
+        }
+        for &child in node.children.iter() {
+            if node.leaf {
+                if matches(claim_bounds(&self.claims[child])) {
+                    found.push(child);
+                }
+            } else {
+                self.search(child, matches, found);
+            }
+        }
+    }
+
+    fn query<F: Fn(Bounds) -> bool>(&self, matches: F) -> Vec<&Claim> {
+        let mut found = Vec::new();
+        // The root is the last node added.
+        if !self.nodes.is_empty() {
+            self.search(self.nodes.len() - 1, &matches, &mut found);
+        }
+        found.sort();
+        found.iter().map(|&i| &self.claims[i]).collect()
+    }
+
+    /// Returns the claims covering the square inch at `(x, y)`, in the order they were given.
+    pub fn claims_at(&self, x: usize, y: usize) -> Vec<&Claim> {
+        self.query(|bounds| contains(bounds, x, y))
+    }
+
+    /// Returns the claims sharing at least one square inch with `rectangle`, in the order they
+    /// were given.
+    pub fn claims_intersecting(&self, rectangle: Rectangle) -> Vec<&Claim> {
+        let (x, y, width, height) = rectangle;
+        // Claims can't extend past the largest coordinate, so neither need the rectangle.
+        self.query(|bounds| overlaps(bounds, (x, y, x.saturating_add(width), y.saturating_add(height))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::Claim;
+    use testing::claims;
+    use testing::random_claims;
+    use super::ClaimIndex;
+
+    fn ids(claims: Vec<&Claim>) -> Vec<usize> {
+        claims.iter().map(|claim| claim.id).collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for seed in 0..20 {
+            let claims = random_claims(seed, 10 * seed as usize, 40, 10);
+            let index = ClaimIndex::new(&claims);
+            assert_eq!(index.claims().len(), claims.len());
+            for y in 0..45 {
+                for x in 0..45 {
+                    let expected: Vec<usize> = claims.iter()
+                        .filter(|claim| claim.contains_point(x, y))
+                        .map(|claim| claim.id)
+                        .collect();
+                    assert_eq!(ids(index.claims_at(x, y)), expected, "seed {} at {},{}", seed, x, y);
+
+                    let area = Claim { id: 0, x, y, width: seed as usize % 7, height: 3 };
+                    let expected: Vec<usize> = claims.iter()
+                        .filter(|claim| claim.intersects(&area))
+                        .map(|claim| claim.id)
+                        .collect();
+                    assert_eq!(ids(index.claims_intersecting(area.rectangle())), expected, "seed {} at {},{}",
+                               seed, x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn handles_edges() {
+        let claims = claims(&["#1 @ 0,0: 2x2", "#2 @ 2,0: 2x2", "#3 @ 1,1: 0x5"]);
+        let index = ClaimIndex::new(&claims);
+        assert_eq!(ids(index.claims_at(1, 1)), vec![1]);
+        assert_eq!(ids(index.claims_at(2, 1)), vec![2]);
+        // Rectangles only touching a claim along an edge, or with no area, don't intersect it.
+        assert_eq!(ids(index.claims_intersecting((0, 2, 4, 4))), vec![]);
+        assert_eq!(ids(index.claims_intersecting((1, 0, 0, 2))), vec![]);
+        assert_eq!(ids(index.claims_intersecting((1, 0, 2, 1))), vec![1, 2]);
+        assert!(ClaimIndex::new(&[]).claims_at(0, 0).is_empty());
+        // Rectangles running past the largest coordinate are cut off there.
+        assert_eq!(ids(index.claims_intersecting((usize::MAX, 0, 1, 1))), vec![]);
+        assert_eq!(ids(index.claims_intersecting((1, 1, usize::MAX, usize::MAX))), vec![1, 2]);
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(test)]
+extern crate bench;
+extern crate solution;
+
+mod claim;
+mod grid;
+mod index;
+pub mod render;
+mod report;
+pub mod sweep;
+#[cfg(test)]
+mod testing;
+
+use solution::Solution;
+
+pub use claim::Claim;
+pub use claim::Rectangle;
+pub use grid::FabricGrid;
+pub use index::ClaimIndex;
+pub use report::overlap_report;
+pub use report::ClaimOverlaps;
+pub use report::OverlapTable;
+
+/// Solution to day three: finding the overlapping fabric claims.
+pub struct DayThree {
+    claims: Vec<Claim>,
+    /// The number of overlapping square inches and the sorted IDs of the claims that overlap no
+    /// other, once the fabric has been mapped.
+    results: Option<(u128, Vec<usize>)>,
+}
+
+impl DayThree {
+    pub fn new() -> DayThree {
+        DayThree {
+            claims: Vec::new(),
+            results: None,
+        }
+    }
+
+    /// Returns the parsed claims.
+    pub fn claims(&self) -> &[Claim] {
+        &self.claims
+    }
+
+    /// Returns the number of square inches of fabric within two or more claims.
+    pub fn overlaps(&mut self) -> u128 {
+        self.map_fabric().0
+    }
+
+    /// Returns the number of square inches of fabric within at least `times` claims, which must
+    /// be at least 1.
+    pub fn covered_area(&self, times: usize) -> u128 {
+        sweep::covered_area(&self.claims, times)
+    }
+
+    /// Returns, for each claim, the other claims it overlaps and by how much.
+    pub fn overlap_report(&self) -> Vec<ClaimOverlaps> {
+        overlap_report(&self.claims)
+    }
+
+    /// Returns the IDs of the claims that don't overlap any other claim, wherever it appears in the
+    /// input, in increasing order.
+    pub fn perfect_claims(&mut self) -> Vec<usize> {
+        self.map_fabric().1.clone()
+    }
+
+    /// Maps the fabric square inch by square inch when it's small enough to, and sweeps across the
+    /// claims otherwise.
+    fn map_fabric(&mut self) -> &(u128, Vec<usize>) {
+        if self.results.is_none() {
+            let (overlaps, mut perfect_claims) = match FabricGrid::new(&self.claims) {
+                Ok(grid) => {
+                    let perfect_claims: Vec<usize> = self.claims.iter()
+                        .filter(|claim| grid.is_alone(claim))
+                        .map(|claim| claim.id)
+                        .collect();
+                    (grid.overlap_area() as u128, perfect_claims)
+                },
+                Err(_) => (sweep::overlap_area(&self.claims), sweep::alone_claims(&self.claims))
+            };
+            perfect_claims.sort();
+            self.results = Some((overlaps, perfect_claims));
+        }
+        self.results.as_ref().unwrap()
+    }
+}
+
+impl Default for DayThree {
+    fn default() -> DayThree {
+        DayThree::new()
+    }
+}
+
+impl Solution for DayThree {
+    fn parse(&mut self, input: &str) -> Result<(), String> {
+        self.claims.clear();
+        self.results = None;
+        for line in input.lines() {
+            self.claims.push(line.parse()?);
+        }
+        Ok(())
+    }
+
+    fn part_one(&mut self) -> String {
+        self.overlaps().to_string()
+    }
+
+    fn part_two(&mut self) -> String {
+        let claims: Vec<String> = self.perfect_claims().iter().map(|id| id.to_string()).collect();
+        claims.join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solution::Solution;
+    use testing::claims;
+    use super::sweep;
+    use super::Claim;
+    use super::DayThree;
+
+    /// Returns every ordering of `items`.
+    fn permutations(items: &[Claim]) -> Vec<Vec<Claim>> {
+        if items.is_empty() {
+            return vec![Vec::new()];
+        }
+        let mut orderings = Vec::new();
+        for i in 0..items.len() {
+            let mut rest = items.to_vec();
+            let first = rest.remove(i);
+            for mut ordering in permutations(&rest) {
+                ordering.insert(0, first);
+                orderings.push(ordering);
+            }
+        }
+        orderings
+    }
+
+    fn day_with(claims: Vec<Claim>) -> DayThree {
+        DayThree { claims, results: None }
+    }
+
+    #[test]
+    fn finds_perfect_claims_in_any_order() {
+        // Claim 3 only covers a square inch that claims 1 and 2 already overlap on, and claim 5
+        // only touches claim 4 along an edge.
+        let claims = claims(&["#1 @ 0,0: 3x3", "#2 @ 1,1: 3x3", "#3 @ 2,2: 1x1", "#4 @ 5,5: 1x1", "#5 @ 6,5: 2x2"]);
+        for ordering in permutations(&claims) {
+            let mut day = day_with(ordering.clone());
+            assert_eq!(day.overlaps(), 4, "{:?}", ordering);
+            assert_eq!(day.perfect_claims(), vec![4, 5], "{:?}", ordering);
+            let mut alone = sweep::alone_claims(day.claims());
+            alone.sort();
+            assert_eq!(alone, vec![4, 5], "{:?}", ordering);
+        }
+    }
+
+    #[test]
+    fn finds_three_way_overlaps() {
+        // Every claim overlaps the other two, and all three share the square inch at 3,1.
+        let claims = claims(&["#1 @ 0,0: 4x2", "#2 @ 3,0: 2x4", "#3 @ 0,1: 4x3"]);
+        for ordering in permutations(&claims) {
+            let mut day = day_with(ordering.clone());
+            assert_eq!(day.overlaps(), 7, "{:?}", ordering);
+            assert!(day.perfect_claims().is_empty(), "{:?}", ordering);
+            assert_eq!(day.part_two(), "", "{:?}", ordering);
+        }
+    }
+
+    #[test]
+    fn solves_huge_fabric() {
+        let mut day = day_with(claims(&["#1 @ 0,0: 200000x200000", "#2 @ 100000,100000: 200000x200000",
+                                        "#3 @ 0,300000: 1x1"]));
+        assert_eq!(day.part_one(), "10000000000");
+        assert_eq!(day.part_two(), "3");
+        let mut day = day_with(claims(&["#1 @ 0,0: 5000000000x5000000000"]));
+        assert_eq!(day.part_one(), "0");
+        assert_eq!(day.part_two(), "1");
+    }
+
+    #[test]
+    fn parses_claims() {
+        let mut day = DayThree::new();
+        day.parse("#1 @ 0,0: 3x3\n#2 @ 1,1: 3x3").unwrap();
+        assert_eq!(day.claims(), &claims(&["#1 @ 0,0: 3x3", "#2 @ 1,1: 3x3"])[..]);
+        assert_eq!(day.parse("#1 @ 0,0: 3x3\n#2"), Err(String::from("invalid claim '#2'")));
+    }
+}
@@ -0,0 +1,87 @@
+extern crate day_three_2018;
+extern crate input;
+extern crate solution;
+
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+use std::process;
+
+use day_three_2018::render;
+use day_three_2018::Claim;
+use day_three_2018::DayThree;
+use day_three_2018::FabricGrid;
+use day_three_2018::OverlapTable;
+use solution::Solution;
+
+fn main() {
+    // Usage: day-three-2018 <input> [--report] [--ppm <image>] [--heatmap <columns>] [--highlight]
+    // --report prints the area covered by each number of claims, and a table of the claims each
+    // claim overlaps. --ppm writes the fabric to a PPM image, and --heatmap prints it as ASCII at
+    // most the given number of columns wide; either is coloured by overlap count, and --highlight
+    // picks out the claims that overlap no other.
+    let args: Vec<String> = env::args().collect();
+    let mut path = None;
+    let mut report = false;
+    let mut ppm_path = None;
+    let mut heatmap_columns = None;
+    let mut highlight = false;
+    let mut args_iter = args.iter().skip(1);
+    while let Some(arg) = args_iter.next() {
+        if arg == "--report" {
+            report = true;
+        } else if arg == "--ppm" {
+            ppm_path = Some(args_iter.next().expect("Missing image file"));
+        } else if arg == "--heatmap" {
+            let columns = args_iter.next().expect("Missing number of heatmap columns");
+            heatmap_columns = Some(columns.parse::<usize>().expect("Invalid number of heatmap columns"));
+        } else if arg == "--highlight" {
+            highlight = true;
+        } else {
+            assert!(path.is_none(), "Incorrect number of args");
+            path = Some(arg);
+        }
+    }
+    let input = input::load_file(path.expect("Incorrect number of args")).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let mut day = DayThree::new();
+    day.parse(&input).unwrap();
+    println!("Overlap segments = {}", day.overlaps());
+    for claim in day.perfect_claims() {
+        println!("Claim {} does not overlap any other claim", claim);
+    }
+
+    if report {
+        for times in 1.. {
+            let area = day.covered_area(times);
+            if area == 0 {
+                break;
+            }
+            println!("Covered by at least {} claims: {}", times, area);
+        }
+        print!("{}", OverlapTable(&day.overlap_report()));
+    }
+
+    if ppm_path.is_some() || heatmap_columns.is_some() {
+        let grid = FabricGrid::new(day.claims()).unwrap_or_else(|e| {
+            eprintln!("Unable to render the fabric: {}", e);
+            process::exit(1);
+        });
+        let perfect_claims = day.perfect_claims();
+        let highlighted: Vec<Claim> = if highlight {
+            day.claims().iter().filter(|claim| perfect_claims.contains(&claim.id)).cloned().collect()
+        } else {
+            Vec::new()
+        };
+        if let Some(ppm_path) = ppm_path {
+            let mut writer = BufWriter::new(File::create(ppm_path).expect("Unable to create image file"));
+            render::write_ppm(&grid, &highlighted, &mut writer).expect("Unable to write image file");
+        }
+        if let Some(columns) = heatmap_columns {
+            print!("{}", render::ascii_heatmap(&grid, &highlighted, columns));
+        }
+    }
+}
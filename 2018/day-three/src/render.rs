@@ -0,0 +1,125 @@
+use std::io;
+use std::io::Write;
+
+use claim::Claim;
+use grid::FabricGrid;
+
+/// Characters for the ASCII heatmap, indexed by claim count (the last is used for any higher
+/// count).
+const HEATMAP_RAMP: &[u8] = b" .:-=+*#%";
+
+/// The character used in the ASCII heatmap for highlighted claims.
+const HEATMAP_HIGHLIGHT: char = 'O';
+
+/// The colour of a square inch of fabric with the given claim count: white when unclaimed, grey
+/// when claimed once, and increasingly deep reds as more claims overlap.
+fn colour(count: u16) -> [u8; 3] {
+    match count {
+        0 => [255, 255, 255],
+        1 => [200, 200, 200],
+        2 => [255, 170, 0],
+        3 => [255, 90, 0],
+        _ => [190, 0, 0]
+    }
+}
+
+/// The colour used for highlighted claims.
+const HIGHLIGHT_COLOUR: [u8; 3] = [0, 160, 0];
+
+/// Writes the fabric as a binary PPM image with one pixel per square inch, coloured by the number
+/// of claims covering it. Square inches within any of the `highlight` claims are drawn in green.
+pub fn write_ppm<W: Write>(grid: &FabricGrid, highlight: &[Claim], writer: &mut W) -> io::Result<()> {
+    let (width, height) = grid.size();
+    write!(writer, "P6\n{} {}\n255\n", width, height)?;
+    let mut row = Vec::with_capacity(3 * width);
+    for y in 0..height {
+        row.clear();
+        for x in 0..width {
+            if highlight.iter().any(|claim| claim.contains_point(x, y)) {
+                row.extend_from_slice(&HIGHLIGHT_COLOUR);
+            } else {
+                row.extend_from_slice(&colour(grid.count(x, y)));
+            }
+        }
+        writer.write_all(&row)?;
+    }
+    Ok(())
+}
+
+/// Renders the fabric as an ASCII heatmap at most `columns` characters wide (and at least one
+/// character wide).
+///
+/// Each character stands for a square block of fabric, and shows the highest claim count within
+/// the block, from ` ` for unclaimed through `.`, `:`, `-` and so on. Blocks containing any square
+/// inch of the `highlight` claims are shown as `O`. Note that terminal characters are usually
+/// taller than they are wide, so the heatmap looks stretched vertically.
+///
+/// # Example
+///
+/// ```
+/// use day_three_2018::render;
+/// use day_three_2018::Claim;
+/// use day_three_2018::FabricGrid;
+///
+/// let claims: Vec<Claim> = ["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4", "#3 @ 5,5: 2x2"].iter()
+///     .map(|claim| claim.parse().unwrap())
+///     .collect();
+/// let grid = FabricGrid::new(&claims).unwrap();
+/// assert_eq!(render::ascii_heatmap(&grid, &claims[2..], 80), "       \n   ....\n   ....\n \
+///                                                             ..::..\n ..::..\n ....OO\n ....OO\n");
+/// ```
+pub fn ascii_heatmap(grid: &FabricGrid, highlight: &[Claim], columns: usize) -> String {
+    let (width, height) = grid.size();
+    let scale = width.div_ceil(columns.max(1)).max(1);
+    let mut heatmap = String::new();
+    for block_y in (0..height).step_by(scale) {
+        for block_x in (0..width).step_by(scale) {
+            let ys = block_y..(block_y + scale).min(height);
+            let xs = block_x..(block_x + scale).min(width);
+            let highlighted = highlight.iter().any(|claim| {
+                ys.clone().any(|y| xs.clone().any(|x| claim.contains_point(x, y)))
+            });
+            if highlighted {
+                heatmap.push(HEATMAP_HIGHLIGHT);
+            } else {
+                let count = ys.clone().flat_map(|y| xs.clone().map(move |x| (x, y)))
+                    .map(|(x, y)| grid.count(x, y))
+                    .max()
+                    .unwrap_or(0);
+                heatmap.push(HEATMAP_RAMP[(count as usize).min(HEATMAP_RAMP.len() - 1)] as char);
+            }
+        }
+        heatmap.push('\n');
+    }
+    heatmap
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::FabricGrid;
+    use testing::claims;
+    use super::ascii_heatmap;
+    use super::write_ppm;
+
+    #[test]
+    fn writes_ppm() {
+        let claims = claims(&["#1 @ 0,0: 2x1", "#2 @ 1,0: 1x1", "#3 @ 0,1: 1x1"]);
+        let grid = FabricGrid::new(&claims).unwrap();
+        let mut image = Vec::new();
+        write_ppm(&grid, &claims[2..], &mut image).unwrap();
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend_from_slice(&[200, 200, 200, 255, 170, 0, 0, 160, 0, 255, 255, 255]);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn downsamples_heatmap() {
+        let claims = claims(&["#1 @ 0,0: 4x4", "#2 @ 2,2: 4x4", "#3 @ 3,3: 1x1", "#4 @ 7,7: 1x1"]);
+        let grid = FabricGrid::new(&claims).unwrap();
+        assert_eq!(ascii_heatmap(&grid, &[], 4), "..  \n.-. \n .. \n   .\n");
+        assert_eq!(ascii_heatmap(&grid, &claims[3..], 4), "..  \n.-. \n .. \n   O\n");
+        // A heatmap can't be narrower than one character.
+        assert_eq!(ascii_heatmap(&grid, &[], 0), "-\n");
+        assert_eq!(ascii_heatmap(&FabricGrid::new(&[]).unwrap(), &[], 80), "");
+    }
+}
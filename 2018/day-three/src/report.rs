@@ -0,0 +1,113 @@
+use std::fmt;
+
+use claim::Claim;
+
+/// How a single claim overlaps the others.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimOverlaps {
+    /// The claim's ID.
+    pub id: usize,
+    /// The number of square inches the claim covers.
+    pub area: u128,
+    /// Each other claim that shares square inches with this one, as an `(id, shared area)` pair,
+    /// in increasing order of ID.
+    pub overlaps: Vec<(usize, u128)>,
+}
+
+/// Finds, for every claim, the other claims it overlaps and how much area they share.
+///
+/// # Returns
+///
+/// * A vector with one entry per claim, in the same order as `claims`.
+///
+/// # Example
+///
+/// ```
+/// use day_three_2018::Claim;
+/// use day_three_2018::overlap_report;
+///
+/// let claims: Vec<Claim> = ["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4", "#3 @ 5,5: 2x2"].iter()
+///     .map(|claim| claim.parse().unwrap())
+///     .collect();
+/// let report = overlap_report(&claims);
+/// assert_eq!(report[0].overlaps, vec![(2, 4)]);
+/// assert_eq!(report[1].overlaps, vec![(1, 4)]);
+/// assert!(report[2].overlaps.is_empty());
+/// ```
+pub fn overlap_report(claims: &[Claim]) -> Vec<ClaimOverlaps> {
+    let mut report: Vec<ClaimOverlaps> = claims.iter()
+        .map(|claim| ClaimOverlaps { id: claim.id, area: claim.area(), overlaps: Vec::new() })
+        .collect();
+
+    // Visit the claims from left to right, so that each claim only needs comparing with the
+    // following claims that start before it ends.
+    let mut order: Vec<usize> = (0..claims.len()).collect();
+    order.sort_by_key(|&i| claims[i].x);
+    for (position, &i) in order.iter().enumerate() {
+        let right = claims[i].x + claims[i].width;
+        for &j in order[position + 1..].iter().take_while(|&&j| claims[j].x < right) {
+            if let Some((_, _, width, height)) = claims[i].intersection(&claims[j]) {
+                let area = width as u128 * height as u128;
+                report[i].overlaps.push((claims[j].id, area));
+                report[j].overlaps.push((claims[i].id, area));
+            }
+        }
+    }
+    for entry in report.iter_mut() {
+        entry.overlaps.sort();
+    }
+    report
+}
+
+/// A table of overlaps, with one row per claim.
+pub struct OverlapTable<'a>(pub &'a [ClaimOverlaps]);
+
+impl<'a> fmt::Display for OverlapTable<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:>6} {:>6} {:>8}  Overlapping claims (id:area)", "Claim", "Area", "Overlaps")?;
+        for entry in self.0.iter() {
+            let overlaps: Vec<String> = entry.overlaps.iter().map(|&(id, area)| format!("{}:{}", id, area)).collect();
+            let row = format!("{:>6} {:>6} {:>8}  {}", entry.id, entry.area, entry.overlaps.len(), overlaps.join(" "));
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::claims;
+    use super::overlap_report;
+    use super::ClaimOverlaps;
+    use super::OverlapTable;
+
+    #[test]
+    fn reports_every_overlap() {
+        let claims = claims(&["#1 @ 0,0: 4x2", "#2 @ 3,0: 2x4", "#3 @ 0,1: 4x3", "#4 @ 4,0: 1x1", "#5 @ 9,9: 1x1"]);
+        assert_eq!(overlap_report(&claims), vec![
+            ClaimOverlaps { id: 1, area: 8, overlaps: vec![(2, 2), (3, 4)] },
+            ClaimOverlaps { id: 2, area: 8, overlaps: vec![(1, 2), (3, 3), (4, 1)] },
+            ClaimOverlaps { id: 3, area: 12, overlaps: vec![(1, 4), (2, 3)] },
+            ClaimOverlaps { id: 4, area: 1, overlaps: vec![(2, 1)] },
+            ClaimOverlaps { id: 5, area: 1, overlaps: vec![] },
+        ]);
+    }
+
+    #[test]
+    fn formats_table() {
+        let report = overlap_report(&claims(&["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4", "#3 @ 5,5: 2x2"]));
+        assert_eq!(OverlapTable(&report).to_string(), " Claim   Area Overlaps  Overlapping claims (id:area)\n     \
+                                                       1     16        1  2:4\n     \
+                                                       2     16        1  1:4\n     \
+                                                       3      4        0\n");
+    }
+
+    #[test]
+    fn reports_huge_claims() {
+        let report = overlap_report(&claims(&["#1 @ 0,0: 5000000000x5000000000", "#2 @ 1,1: 5000000000x1"]));
+        assert_eq!(report, vec![
+            ClaimOverlaps { id: 1, area: 25_000_000_000_000_000_000, overlaps: vec![(2, 4_999_999_999)] },
+            ClaimOverlaps { id: 2, area: 5_000_000_000, overlaps: vec![(1, 4_999_999_999)] },
+        ]);
+    }
+}
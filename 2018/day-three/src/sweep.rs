@@ -0,0 +1,343 @@
+//! Overlap computations that sweep a line across the claims instead of mapping every square inch,
+//! so they take O(n log n) time for n claims no matter how large the fabric is.
+
+use claim::Claim;
+
+/// Returns the sorted, distinct values of `coordinates`.
+fn compress<I: Iterator<Item = usize>>(coordinates: I) -> Vec<usize> {
+    let mut coordinates: Vec<usize> = coordinates.collect();
+    coordinates.sort();
+    coordinates.dedup();
+    coordinates
+}
+
+/// Returns the index of `value` in `coordinates`, which must contain it.
+fn index_of(coordinates: &[usize], value: usize) -> usize {
+    coordinates.binary_search(&value).unwrap()
+}
+
+/// Claims that cover no square inches can't overlap anything, so the sweeps skip them.
+fn has_area(claim: &&Claim) -> bool {
+    claim.width > 0 && claim.height > 0
+}
+
+/// A segment tree over the gaps between sorted coordinates, tracking how much of the line is
+/// covered by at least 1, 2, ..., `depth` of the intervals added so far.
+struct CoverageTree {
+    coordinates: Vec<usize>,
+    depth: usize,
+    /// The number of intervals covering each node's whole range, without being split further.
+    cover: Vec<usize>,
+    /// For each node, `depth` lengths: the length of its range covered at least 1, 2, ... times.
+    covered: Vec<u128>,
+}
+
+impl CoverageTree {
+    fn new(coordinates: Vec<usize>, depth: usize) -> CoverageTree {
+        let nodes = 4 * coordinates.len().max(1);
+        CoverageTree { coordinates, depth, cover: vec![0; nodes], covered: vec![0; nodes * depth] }
+    }
+
+    /// Returns the length covered at least `depth` times, as recorded at the root (node 1).
+    fn covered(&self) -> u128 {
+        if self.coordinates.len() < 2 { 0 } else { self.covered[self.depth + self.depth - 1] }
+    }
+
+    /// Adds `delta` to the cover of the gaps from `coordinates[start]` to `coordinates[end]`.
+    fn update(&mut self, start: usize, end: usize, delta: isize) {
+        if start < end {
+            let gaps = self.coordinates.len() - 1;
+            self.update_node(1, 0, gaps, start, end, delta);
+        }
+    }
+
+    fn update_node(&mut self, node: usize, lo: usize, hi: usize, start: usize, end: usize, delta: isize) {
+        if end <= lo || hi <= start {
+            return;
+        }
+        if start <= lo && hi <= end {
+            self.cover[node] = (self.cover[node] as isize + delta) as usize;
+        } else {
+            let mid = (lo + hi) / 2;
+            self.update_node(2 * node, lo, mid, start, end, delta);
+            self.update_node(2 * node + 1, mid, hi, start, end, delta);
+        }
+        let length = (self.coordinates[hi] - self.coordinates[lo]) as u128;
+        let cover = self.cover[node];
+        for times in 1..=self.depth {
+            self.covered[node * self.depth + times - 1] = if cover >= times {
+                length
+            } else if hi - lo == 1 {
+                0
+            } else {
+                // The children need only make up the cover this node doesn't provide itself.
+                let needed = times - cover - 1;
+                self.covered[2 * node * self.depth + needed] + self.covered[(2 * node + 1) * self.depth + needed]
+            };
+        }
+    }
+}
+
+/// A segment tree holding one value per position, supporting a range update that combines each
+/// value with another using `combine` (which must distribute over `max`), and a range maximum.
+struct MaxTree {
+    max: Vec<i64>,
+    pending: Vec<i64>,
+    identity: i64,
+    combine: fn(i64, i64) -> i64,
+    len: usize,
+}
+
+impl MaxTree {
+    fn new(len: usize, identity: i64, combine: fn(i64, i64) -> i64) -> MaxTree {
+        let nodes = 4 * len.max(1);
+        MaxTree { max: vec![identity; nodes], pending: vec![identity; nodes], identity, combine, len }
+    }
+
+    fn apply(&mut self, node: usize, value: i64) {
+        self.max[node] = (self.combine)(self.max[node], value);
+        self.pending[node] = (self.combine)(self.pending[node], value);
+    }
+
+    fn push_down(&mut self, node: usize) {
+        let pending = self.pending[node];
+        if pending != self.identity {
+            self.apply(2 * node, pending);
+            self.apply(2 * node + 1, pending);
+            self.pending[node] = self.identity;
+        }
+    }
+
+    /// Combines every value in `start..end` with `value`.
+    fn update(&mut self, start: usize, end: usize, value: i64) {
+        let len = self.len;
+        self.update_node(1, 0, len, start, end, value);
+    }
+
+    fn update_node(&mut self, node: usize, lo: usize, hi: usize, start: usize, end: usize, value: i64) {
+        if end <= lo || hi <= start {
+            return;
+        }
+        if start <= lo && hi <= end {
+            self.apply(node, value);
+            return;
+        }
+        self.push_down(node);
+        let mid = (lo + hi) / 2;
+        self.update_node(2 * node, lo, mid, start, end, value);
+        self.update_node(2 * node + 1, mid, hi, start, end, value);
+        self.max[node] = self.max[2 * node].max(self.max[2 * node + 1]);
+    }
+
+    /// Returns the largest value in `start..end`.
+    fn max(&mut self, start: usize, end: usize) -> i64 {
+        let len = self.len;
+        self.max_node(1, 0, len, start, end)
+    }
+
+    fn max_node(&mut self, node: usize, lo: usize, hi: usize, start: usize, end: usize) -> i64 {
+        if end <= lo || hi <= start {
+            return i64::MIN;
+        }
+        if start <= lo && hi <= end {
+            return self.max[node];
+        }
+        self.push_down(node);
+        let mid = (lo + hi) / 2;
+        self.max_node(2 * node, lo, mid, start, end).max(self.max_node(2 * node + 1, mid, hi, start, end))
+    }
+}
+
+/// Returns the largest number of `claims` covering any one square inch.
+fn max_coverage(claims: &[&Claim]) -> usize {
+    let ys = compress(claims.iter().flat_map(|claim| vec![claim.y, claim.y + claim.height]));
+    // Sweep across the fabric as `alone_claims` does, removing claims before adding any at the
+    // same column.
+    let mut events: Vec<(usize, bool, usize, usize)> = Vec::with_capacity(2 * claims.len());
+    for claim in claims.iter() {
+        let start = index_of(&ys, claim.y);
+        let end = index_of(&ys, claim.y + claim.height);
+        events.push((claim.x, true, start, end));
+        events.push((claim.x + claim.width, false, start, end));
+    }
+    events.sort();
+
+    let rows = ys.len().saturating_sub(1);
+    let mut counts = MaxTree::new(rows, 0, |count, delta| count + delta);
+    let mut max = 0;
+    for (_, adding, start, end) in events {
+        counts.update(start, end, if adding { 1 } else { -1 });
+        max = max.max(counts.max(0, rows));
+    }
+    max as usize
+}
+
+/// Returns the number of square inches covered by at least `times` claims, which must be at
+/// least 1.
+///
+/// # Example
+///
+/// ```
+/// use day_three_2018::Claim;
+/// use day_three_2018::sweep;
+///
+/// let claims: Vec<Claim> = ["#1 @ 0,0: 4x4", "#2 @ 2,2: 4x4", "#3 @ 3,3: 1x1"].iter()
+///     .map(|claim| claim.parse().unwrap())
+///     .collect();
+/// assert_eq!(sweep::covered_area(&claims, 1), 28);
+/// assert_eq!(sweep::covered_area(&claims, 3), 1);
+/// ```
+pub fn covered_area(claims: &[Claim], times: usize) -> u128 {
+    assert!(times > 0, "Area must be covered at least once");
+    let claims: Vec<&Claim> = claims.iter().filter(has_area).collect();
+    // The coverage tree keeps `times` lengths per node, so don't build one deeper than the claims
+    // ever stack.
+    if times > claims.len() || times > max_coverage(&claims) {
+        return 0;
+    }
+    let xs = compress(claims.iter().flat_map(|claim| vec![claim.x, claim.x + claim.width]));
+
+    // Sweep down the fabric, adding each claim's columns at its top edge and removing them at
+    // its bottom edge.
+    let mut events: Vec<(usize, isize, usize, usize)> = Vec::with_capacity(2 * claims.len());
+    for claim in claims.iter() {
+        let start = index_of(&xs, claim.x);
+        let end = index_of(&xs, claim.x + claim.width);
+        events.push((claim.y, 1, start, end));
+        events.push((claim.y + claim.height, -1, start, end));
+    }
+    events.sort();
+
+    let mut tree = CoverageTree::new(xs, times);
+    let mut area = 0;
+    let mut last_y = 0;
+    for (y, delta, start, end) in events {
+        area += tree.covered() * (y - last_y) as u128;
+        tree.update(start, end, delta);
+        last_y = y;
+    }
+    area
+}
+
+/// Returns the number of square inches covered by two or more claims.
+///
+/// # Example
+///
+/// ```
+/// use day_three_2018::Claim;
+/// use day_three_2018::sweep;
+///
+/// let claims: Vec<Claim> = ["#1 @ 1,3: 4x4", "#2 @ 3,1: 4x4", "#3 @ 5,5: 2x2"].iter()
+///     .map(|claim| claim.parse().unwrap())
+///     .collect();
+/// assert_eq!(sweep::overlap_area(&claims), 4);
+/// ```
+pub fn overlap_area(claims: &[Claim]) -> u128 {
+    covered_area(claims, 2)
+}
+
+/// Returns the IDs of the claims that share no square inches with any other claim, in the order
+/// the claims are given.
+pub fn alone_claims(claims: &[Claim]) -> Vec<usize> {
+    let ys = compress(claims.iter().filter(has_area).flat_map(|claim| vec![claim.y, claim.y + claim.height]));
+    let rows = ys.len().saturating_sub(1);
+
+    // Sweep across the fabric from left to right, adding each claim's rows at its left edge and
+    // removing them at its right edge. Removals come first, since claims that only touch along an
+    // edge don't overlap.
+    let mut events: Vec<(usize, bool, usize)> = Vec::with_capacity(2 * claims.len());
+    for (index, claim) in claims.iter().enumerate().filter(|&(_, claim)| has_area(&claim)) {
+        events.push((claim.x, true, index));
+        events.push((claim.x + claim.width, false, index));
+    }
+    events.sort();
+
+    // The number of claims currently covering each row, and the last time a claim was added
+    // across each row.
+    let mut counts = MaxTree::new(rows, 0, |count, delta| count + delta);
+    let mut added = MaxTree::new(rows, i64::MIN, |time, new_time| time.max(new_time));
+    let mut added_at = vec![0; claims.len()];
+    let mut overlapped = vec![false; claims.len()];
+    for (time, &(_, adding, index)) in events.iter().enumerate() {
+        let claim = &claims[index];
+        let start = index_of(&ys, claim.y);
+        let end = index_of(&ys, claim.y + claim.height);
+        if adding {
+            // This claim overlaps any claim already covering one of its rows...
+            if counts.max(start, end) > 0 {
+                overlapped[index] = true;
+            }
+            counts.update(start, end, 1);
+            added.update(start, end, time as i64);
+            added_at[index] = time as i64;
+        } else {
+            // ... and any claim added across one of its rows while it was in place.
+            if added.max(start, end) > added_at[index] {
+                overlapped[index] = true;
+            }
+            counts.update(start, end, -1);
+        }
+    }
+    claims.iter().zip(overlapped).filter(|&(_, overlapped)| !overlapped).map(|(claim, _)| claim.id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::Claim;
+    use grid::FabricGrid;
+    use testing::claims;
+    use testing::random_claims;
+    use super::alone_claims;
+    use super::covered_area;
+    use super::overlap_area;
+
+    #[test]
+    fn matches_grid() {
+        for seed in 0..50 {
+            let size = 10 + seed as usize;
+            let claims = random_claims(seed, 1 + seed as usize % 25, size, size / 3);
+            let grid = FabricGrid::new(&claims).unwrap();
+            for times in 1..8 {
+                assert_eq!(covered_area(&claims, times), grid.covered_area(times as u16) as u128, "seed {}", seed);
+            }
+            let alone: Vec<usize> = claims.iter().filter(|claim| grid.is_alone(claim)).map(|claim| claim.id).collect();
+            assert_eq!(alone_claims(&claims), alone, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn counts_deeper_coverage() {
+        let claims = claims(&["#1 @ 0,0: 4x4", "#2 @ 2,2: 4x4", "#3 @ 3,3: 1x1", "#4 @ 1,1: 2x2"]);
+        assert_eq!(covered_area(&claims, 1), 28);
+        assert_eq!(covered_area(&claims, 2), 7);
+        assert_eq!(covered_area(&claims, 3), 2);
+        assert_eq!(covered_area(&claims, 4), 0);
+        assert_eq!(covered_area(&claims, 5), 0);
+        assert_eq!(covered_area(&claims, 1_000_000), 0);
+        assert_eq!(covered_area(&claims, usize::MAX), 0);
+    }
+
+    #[test]
+    fn handles_huge_fabric() {
+        let huge = 1 << 40;
+        let claims = vec![
+            Claim { id: 1, x: 0, y: 0, width: huge, height: huge },
+            Claim { id: 2, x: huge / 2, y: huge / 2, width: huge, height: huge },
+            Claim { id: 3, x: 2 * huge, y: 0, width: huge, height: 1 },
+        ];
+        assert_eq!(overlap_area(&claims), (huge as u128 / 2).pow(2));
+        assert_eq!(alone_claims(&claims), vec![3]);
+    }
+
+    #[test]
+    fn handles_empty_claims() {
+        let claims = vec![
+            Claim { id: 1, x: 0, y: 0, width: 0, height: 5 },
+            Claim { id: 2, x: 0, y: 0, width: 5, height: 5 },
+        ];
+        assert_eq!(overlap_area(&claims), 0);
+        assert_eq!(alone_claims(&claims), vec![1, 2]);
+        assert_eq!(overlap_area(&[]), 0);
+        assert!(alone_claims(&[]).is_empty());
+    }
+}
@@ -0,0 +1,29 @@
+//! Helpers shared by the unit tests.
+
+use bench::Lcg;
+use claim::Claim;
+
+/// Parses a claim, e.g. `#123 @ 3,2: 5x4`.
+pub fn claim(claim: &str) -> Claim {
+    claim.parse().unwrap()
+}
+
+/// Parses each of `claims`.
+pub fn claims(claims: &[&str]) -> Vec<Claim> {
+    claims.iter().map(|&c| claim(c)).collect()
+}
+
+/// Generates `count` pseudo-random claims on a fabric roughly `size` square inches across, each
+/// less than `max_side` square inches wide and high.
+pub fn random_claims(seed: u64, count: usize, size: usize, max_side: usize) -> Vec<Claim> {
+    let mut lcg = Lcg::new(seed);
+    (1..=count)
+        .map(|id| Claim {
+            id,
+            x: lcg.below(size),
+            y: lcg.below(size),
+            width: lcg.below(max_side),
+            height: lcg.below(max_side),
+        })
+        .collect()
+}
@@ -0,0 +1,31 @@
+extern crate bench;
+extern crate day_two_2018;
+extern crate solution;
+
+use bench::Lcg;
+use day_two_2018::DayTwo;
+use day_two_2018::Detector;
+use solution::Solution;
+
+/// Generates `count` random IDs of `len` lowercase letters, with a near-duplicate of the first ID
+/// placed at the end so that both detectors have to scan the whole input.
+fn generate_ids(count: usize, len: usize) -> String {
+    let mut lcg = Lcg::new(0x2018_0002);
+    let mut next_letter = || (b'a' + lcg.below(26) as u8) as char;
+    let mut ids: Vec<String> = (0..count - 1).map(|_| (0..len).map(|_| next_letter()).collect()).collect();
+    let mut near: Vec<char> = ids[0].chars().collect();
+    near[len / 2] = if near[len / 2] == 'a' { 'b' } else { 'a' };
+    ids.push(near.into_iter().collect());
+    ids.join("\n")
+}
+
+fn main() {
+    for &(count, len) in [(250, 26), (1_000, 26), (10_000, 26), (1_000, 100)].iter() {
+        let mut day = DayTwo::new();
+        day.parse(&generate_ids(count, len)).unwrap();
+        let (trie_time, trie_result) = bench::fastest(|| day.off_by_one_using(Detector::Trie));
+        let (hash_time, hash_result) = bench::fastest(|| day.off_by_one_using(Detector::HashBucket));
+        assert_eq!(trie_result, hash_result, "Detectors disagree on {} IDs of length {}", count, len);
+        println!("{:>6} IDs of length {:>3}: trie {:>12?}, hash buckets {:>12?}", count, len, trie_time, hash_time);
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Returns the number of times each letter appears in `id`.
+///
+/// # Example
+///
+/// ```
+/// use day_two_2018::letter_counts;
+///
+/// let counts = letter_counts("bababc");
+/// assert_eq!(counts[&'a'], 2);
+/// assert_eq!(counts[&'b'], 3);
+/// assert_eq!(counts[&'c'], 1);
+/// ```
+pub fn letter_counts(id: &str) -> HashMap<char, usize> {
+    let mut map = HashMap::new();
+    for c in id.chars() {
+        let count = map.entry(c).or_insert(0);
+        *count += 1;
+    }
+    map
+}
+
+/// Counts, for each of the given multiplicities, how many IDs contain at least one letter that
+/// appears exactly that many times. An ID with several letters of the same multiplicity only
+/// counts once towards it.
+///
+/// # Arguments
+///
+/// * `ids` - The box IDs to scan.
+/// * `multiplicities` - The letter counts of interest (e.g. `[2, 3]` for part one).
+///
+/// # Returns
+///
+/// * A vector with one count per multiplicity, in the same order as `multiplicities`.
+///
+/// # Example
+///
+/// ```
+/// use day_two_2018::multiplicity_counts;
+///
+/// let ids = ["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"];
+/// assert_eq!(multiplicity_counts(&ids, &[2, 3]), vec![4, 3]);
+/// ```
+pub fn multiplicity_counts<S: AsRef<str>>(ids: &[S], multiplicities: &[usize]) -> Vec<usize> {
+    let mut totals = vec![0; multiplicities.len()];
+    for id in ids {
+        let present: HashSet<usize> = letter_counts(id.as_ref()).values().cloned().collect();
+        for (total, multiplicity) in totals.iter_mut().zip(multiplicities) {
+            if present.contains(multiplicity) {
+                *total += 1;
+            }
+        }
+    }
+    totals
+}
+
+/// Returns the checksum of the IDs: the product of `multiplicity_counts` over the given
+/// multiplicities. Part one of the puzzle uses the multiplicities `[2, 3]`.
+///
+/// # Example
+///
+/// ```
+/// use day_two_2018::checksum;
+///
+/// let ids = ["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"];
+/// assert_eq!(checksum(&ids, &[2, 3]), 12);
+/// ```
+pub fn checksum<S: AsRef<str>>(ids: &[S], multiplicities: &[usize]) -> usize {
+    multiplicity_counts(ids, multiplicities).iter().product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::checksum;
+    use super::letter_counts;
+    use super::multiplicity_counts;
+
+    const EXAMPLE: [&str; 7] = ["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"];
+
+    #[test]
+    fn counts_letters() {
+        let counts = letter_counts("abcccd");
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts[&'c'], 3);
+        assert!(letter_counts("").is_empty());
+    }
+
+    #[test]
+    fn counts_each_id_once_per_multiplicity() {
+        // "aabbcc" has three letters appearing twice, but is only one ID with a double.
+        assert_eq!(multiplicity_counts(&["aabbcc", "abc"], &[1, 2, 3]), vec![1, 1, 0]);
+    }
+
+    #[test]
+    fn example_checksum() {
+        assert_eq!(multiplicity_counts(&EXAMPLE, &[2, 3]), vec![4, 3]);
+        assert_eq!(checksum(&EXAMPLE, &[2, 3]), 12);
+    }
+
+    #[test]
+    fn other_multiplicities() {
+        assert_eq!(multiplicity_counts(&EXAMPLE, &[1, 4, 5]), vec![6, 0, 0]);
+        assert_eq!(checksum(&EXAMPLE, &[1, 2]), 24);
+        assert_eq!(checksum(&EXAMPLE, &[]), 1);
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use checksum::letter_counts;
+
+/// Letter statistics over a set of box IDs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LetterHistogram {
+    /// The number of times each letter appears across all IDs.
+    pub letter_totals: BTreeMap<char, usize>,
+    /// For each position (counting from 0), the number of IDs with each letter at that position.
+    pub positions: Vec<BTreeMap<char, usize>>,
+    /// The number of IDs whose most repeated letter appears each number of times. An empty ID has
+    /// a maximum multiplicity of 0.
+    pub max_multiplicities: BTreeMap<usize, usize>,
+}
+
+/// Builds the letter histogram for `ids`.
+///
+/// # Example
+///
+/// ```
+/// use day_two_2018::letter_histogram;
+///
+/// let histogram = letter_histogram(&["abcdef", "bababc", "abbcde"]);
+/// assert_eq!(histogram.letter_totals[&'b'], 6);
+/// assert_eq!(histogram.positions[0][&'a'], 2);
+/// assert_eq!(histogram.max_multiplicities[&3], 1);
+/// ```
+pub fn letter_histogram<S: AsRef<str>>(ids: &[S]) -> LetterHistogram {
+    let mut histogram = LetterHistogram::default();
+    for id in ids.iter().map(|id| id.as_ref()) {
+        let counts = letter_counts(id);
+        for (c, count) in counts.iter() {
+            *histogram.letter_totals.entry(*c).or_insert(0) += count;
+        }
+        let max = counts.values().cloned().max().unwrap_or(0);
+        *histogram.max_multiplicities.entry(max).or_insert(0) += 1;
+
+        for (i, c) in id.chars().enumerate() {
+            if histogram.positions.len() <= i {
+                histogram.positions.push(BTreeMap::new());
+            }
+            *histogram.positions[i].entry(c).or_insert(0) += 1;
+        }
+    }
+    histogram
+}
+
+/// Writes one line of `letter:count` pairs, most frequent first.
+fn write_counts(f: &mut fmt::Formatter, counts: &BTreeMap<char, usize>) -> fmt::Result {
+    let mut counts: Vec<(&char, &usize)> = counts.iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    let counts: Vec<String> = counts.iter().map(|&(c, count)| format!("{}:{}", c, count)).collect();
+    writeln!(f, "{}", counts.join(" "))
+}
+
+impl fmt::Display for LetterHistogram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Letter totals: ")?;
+        write_counts(f, &self.letter_totals)?;
+        writeln!(f, "Letters by position:")?;
+        for (i, counts) in self.positions.iter().enumerate() {
+            write!(f, "  {:>3}: ", i)?;
+            write_counts(f, counts)?;
+        }
+        writeln!(f, "IDs by most repeated letter count:")?;
+        for (multiplicity, ids) in self.max_multiplicities.iter() {
+            writeln!(f, "  {:>3}: {}", multiplicity, ids)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::letter_histogram;
+
+    const EXAMPLE: [&str; 7] = ["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"];
+
+    #[test]
+    fn builds_histogram() {
+        let histogram = letter_histogram(&EXAMPLE);
+        assert_eq!(histogram.letter_totals.values().sum::<usize>(), 42);
+        assert_eq!(histogram.letter_totals[&'a'], 11);
+        assert_eq!(histogram.letter_totals[&'f'], 1);
+
+        assert_eq!(histogram.positions.len(), 6);
+        assert_eq!(histogram.positions[0].get(&'a'), Some(&6));
+        assert_eq!(histogram.positions[0].get(&'b'), Some(&1));
+        assert_eq!(histogram.positions[5].get(&'b'), Some(&1));
+
+        let max: Vec<(usize, usize)> = histogram.max_multiplicities.into_iter().collect();
+        assert_eq!(max, vec![(1, 1), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn handles_uneven_lengths() {
+        let histogram = letter_histogram(&["ab", "", "abca"]);
+        assert_eq!(histogram.positions.len(), 4);
+        assert_eq!(histogram.positions[3].get(&'a'), Some(&1));
+        assert_eq!(histogram.max_multiplicities.get(&0), Some(&1));
+        assert_eq!(histogram.max_multiplicities.get(&2), Some(&1));
+    }
+
+    #[test]
+    fn formats_report() {
+        let report = letter_histogram(&["aab", "abb"]).to_string();
+        assert_eq!(report, "Letter totals: a:3 b:3\n\
+                            Letters by position:\n    0: a:2\n    1: a:1 b:1\n    2: b:2\n\
+                            IDs by most repeated letter count:\n    2: 2\n");
+    }
+}
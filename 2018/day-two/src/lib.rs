@@ -0,0 +1,100 @@
+extern crate solution;
+extern crate trie;
+
+mod checksum;
+mod histogram;
+mod near_duplicates;
+
+use solution::Solution;
+use trie::Trie;
+
+pub use checksum::checksum;
+pub use checksum::letter_counts;
+pub use checksum::multiplicity_counts;
+pub use histogram::letter_histogram;
+pub use histogram::LetterHistogram;
+pub use near_duplicates::near_duplicates;
+pub use near_duplicates::off_by_one_hashed;
+pub use near_duplicates::Detector;
+pub use near_duplicates::NearDuplicate;
+
+/// Solution to day two: checksumming box IDs and finding the two prototype fabric boxes.
+pub struct DayTwo {
+    ids: Vec<String>,
+}
+
+impl DayTwo {
+    pub fn new() -> DayTwo {
+        DayTwo { ids: Vec::new() }
+    }
+
+    /// Returns the parsed box IDs.
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+
+    /// Returns the number of IDs containing a letter exactly twice, and the number containing a
+    /// letter exactly three times, as a `(doubles, triples)` pair.
+    pub fn counts(&self) -> (usize, usize) {
+        let counts = multiplicity_counts(&self.ids, &[2, 3]);
+        (counts[0], counts[1])
+    }
+
+    /// Returns letter statistics over the IDs.
+    pub fn histogram(&self) -> LetterHistogram {
+        letter_histogram(&self.ids)
+    }
+
+    /// Returns the letters common to the first pair of IDs that differ by exactly one character.
+    pub fn off_by_one(&self) -> Option<String> {
+        self.off_by_one_using(Detector::Trie)
+    }
+
+    /// Returns the letters common to the first pair of IDs that differ by exactly one character,
+    /// found with the given detector.
+    pub fn off_by_one_using(&self, detector: Detector) -> Option<String> {
+        match detector {
+            Detector::Trie => self.off_by_one_trie(),
+            Detector::HashBucket => off_by_one_hashed(&self.ids)
+        }
+    }
+
+    fn off_by_one_trie(&self) -> Option<String> {
+        let mut trie = Trie::new();
+        for string in self.ids.iter() {
+            let maybe_match = trie.match_off_by_one(string);
+            if maybe_match.is_some() {
+                // We don't need to keep adding more strings if we already found the match.
+                return maybe_match;
+            }
+            trie.insert(string.clone());
+        }
+        None
+    }
+
+    /// Returns every pair of IDs that differ by exactly one character.
+    pub fn near_duplicates(&self) -> Vec<NearDuplicate> {
+        near_duplicates(&self.ids)
+    }
+}
+
+impl Default for DayTwo {
+    fn default() -> DayTwo {
+        DayTwo::new()
+    }
+}
+
+impl Solution for DayTwo {
+    fn parse(&mut self, input: &str) -> Result<(), String> {
+        self.ids = input.lines().map(|line| line.to_string()).collect();
+        Ok(())
+    }
+
+    fn part_one(&mut self) -> String {
+        checksum(&self.ids, &[2, 3]).to_string()
+    }
+
+    fn part_two(&mut self) -> String {
+        self.off_by_one().unwrap_or_default()
+    }
+}
@@ -0,0 +1,64 @@
+extern crate day_two_2018;
+extern crate input;
+extern crate solution;
+
+use std::env;
+use std::process;
+
+use day_two_2018::DayTwo;
+use day_two_2018::Detector;
+use solution::Solution;
+
+fn main() {
+    // Usage: day-two-2018 <input> [--all-pairs] [--detector trie|hash] [--histogram]
+    // --all-pairs lists every pair of IDs that differ by one character, not just the first.
+    // --histogram prints letter statistics for the IDs.
+    // --detector chooses how the first such pair is found (a trie by default).
+    let args: Vec<String> = env::args().collect();
+    let mut path = None;
+    let mut all_pairs = false;
+    let mut histogram = false;
+    let mut detector = Detector::Trie;
+    let mut args_iter = args.iter().skip(1);
+    while let Some(arg) = args_iter.next() {
+        if arg == "--detector" {
+            detector = match args_iter.next().map(|s| s.as_str()) {
+                Some("trie") => Detector::Trie,
+                Some("hash") => Detector::HashBucket,
+                Some(other) => panic!("Unknown detector {}", other),
+                None => panic!("Missing detector")
+            };
+        } else if arg == "--histogram" {
+            histogram = true;
+        } else if arg == "--all-pairs" {
+            all_pairs = true;
+        } else {
+            assert!(path.is_none(), "Incorrect number of args");
+            path = Some(arg);
+        }
+    }
+    let input = input::load_file(path.expect("Incorrect number of args")).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let mut day = DayTwo::new();
+    day.parse(&input).unwrap();
+    let (double_count, triple_count) = day.counts();
+    println!("Doubles: {}, Triples: {}, Checksum: {}",
+             double_count, triple_count, double_count*triple_count);
+    match day.off_by_one_using(detector) {
+        Some(string) => println!("Found off by one: {}", string),
+        None => println!("Did not find off by one")
+    }
+    if all_pairs {
+        let pairs = day.near_duplicates();
+        println!("Found {} off by one pairs", pairs.len());
+        for pair in pairs {
+            println!("{} {} differ at index {}, common: {}", pair.first, pair.second, pair.index, pair.common);
+        }
+    }
+    if histogram {
+        print!("{}", day.histogram());
+    }
+}
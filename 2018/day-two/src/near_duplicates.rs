@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use trie::Trie;
+
+/// The ways of finding a pair of IDs that differ by exactly one character.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Detector {
+    /// Searches a trie of the IDs seen so far, using `Trie::match_off_by_one`.
+    Trie,
+    /// Removes each character from each ID in turn and looks for two IDs that collide once the
+    /// same position has been removed.
+    HashBucket,
+}
+
+/// A pair of box IDs that differ by exactly one character.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NearDuplicate {
+    /// The ID that appears first in the input.
+    pub first: String,
+    /// The ID that appears second in the input.
+    pub second: String,
+    /// The position of the character that differs between the two IDs, counting from 0.
+    pub index: usize,
+    /// The characters the two IDs have in common, i.e. either ID with the differing character
+    /// removed.
+    pub common: String,
+}
+
+/// Finds every pair of IDs that differ by exactly one character (at the same position).
+///
+/// # Returns
+///
+/// * A vector with one entry per pair, ordered by where the second ID of the pair appears in
+///   `ids`, and then by the first ID.
+///
+/// # Example
+///
+/// ```
+/// use day_two_2018::near_duplicates;
+///
+/// let pairs = near_duplicates(&["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz"]);
+/// assert_eq!(pairs.len(), 1);
+/// assert_eq!(pairs[0].first, "fghij");
+/// assert_eq!(pairs[0].second, "fguij");
+/// assert_eq!(pairs[0].index, 2);
+/// assert_eq!(pairs[0].common, "fgij");
+/// ```
+pub fn near_duplicates<S: AsRef<str>>(ids: &[S]) -> Vec<NearDuplicate> {
+    let mut trie = Trie::new();
+    let mut pairs = Vec::new();
+    for id in ids.iter().map(|id| id.as_ref()) {
+        for (first, index) in trie.matches_off_by_one(id) {
+            pairs.push(NearDuplicate {
+                first: first.clone(),
+                second: id.to_string(),
+                index,
+                common: remove_char(id, index),
+            });
+        }
+        trie.insert(id.to_string());
+    }
+    pairs
+}
+
+/// The multipliers for the polynomial hashes of the prefixes and suffixes of IDs.
+const PREFIX_BASE: u64 = 0x0000_0100_0000_01b3;
+const SUFFIX_BASE: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Returns the hashes of the first 0, 1, 2, ... characters of `chars`, in that order.
+fn prefix_hashes<I: Iterator<Item = char>>(chars: I, base: u64) -> Vec<u64> {
+    let mut hashes = vec![0u64];
+    for c in chars {
+        let hash = hashes[hashes.len() - 1].wrapping_mul(base).wrapping_add(u64::from(c) + 1);
+        hashes.push(hash);
+    }
+    hashes
+}
+
+/// Returns whether `a` and `b` have the same characters everywhere except at position `index`.
+fn same_except_at(a: &str, b: &str, index: usize) -> bool {
+    a.chars().count() == b.chars().count()
+        && a.chars().zip(b.chars()).enumerate().all(|(i, (x, y))| i == index || x == y)
+}
+
+/// Finds the letters common to the first pair of IDs that differ by exactly one character, by
+/// hashing every ID with each position removed in turn. The first pair is the one whose second ID
+/// appears earliest in `ids`.
+///
+/// Each ID's prefixes and suffixes are hashed once, so the key for removing any one position
+/// takes constant time to build. This takes time proportional to the total length of the IDs,
+/// regardless of how the IDs are distributed; IDs are only compared in full when their keys
+/// collide.
+///
+/// # Example
+///
+/// ```
+/// use day_two_2018::off_by_one_hashed;
+///
+/// let ids = ["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz"];
+/// assert_eq!(off_by_one_hashed(&ids), Some(String::from("fgij")));
+/// assert_eq!(off_by_one_hashed(&["abcde", "axcye"]), None);
+/// ```
+pub fn off_by_one_hashed<S: AsRef<str>>(ids: &[S]) -> Option<String> {
+    // Each bucket is keyed by the ID's length and the removed position as well as the hashes of
+    // the characters either side of it, so that e.g. "ab" and "ba" don't collide on "a" or "b".
+    // Repeats of an ID are skipped: any ID they match would already have matched the first one.
+    let mut buckets: HashMap<(usize, usize, u64, u64), Vec<&str>> = HashMap::new();
+    let mut seen: HashSet<&str> = HashSet::new();
+    for id in ids.iter().map(|id| id.as_ref()) {
+        if !seen.insert(id) {
+            continue;
+        }
+        let len = id.chars().count();
+        let prefixes = prefix_hashes(id.chars(), PREFIX_BASE);
+        let suffixes = prefix_hashes(id.chars().rev(), SUFFIX_BASE);
+        let keys: Vec<(usize, usize, u64, u64)> = (0..len)
+            .map(|i| (len, i, prefixes[i], suffixes[len - 1 - i]))
+            .collect();
+        for key in keys.iter() {
+            let index = key.1;
+            let matched = buckets.get(key)
+                .is_some_and(|others| others.iter().any(|other| same_except_at(other, id, index)));
+            if matched {
+                return Some(remove_char(id, index));
+            }
+        }
+        for key in keys {
+            buckets.entry(key).or_default().push(id);
+        }
+    }
+    None
+}
+
+/// Returns `id` with the character at position `index` removed.
+pub(crate) fn remove_char(id: &str, index: usize) -> String {
+    id.chars().enumerate().filter(|&(i, _)| i != index).map(|(_, c)| c).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::near_duplicates;
+    use super::off_by_one_hashed;
+
+    #[test]
+    fn finds_every_pair() {
+        let pairs = near_duplicates(&["abcd", "abce", "xbcd", "abcd", "wxyz", "abzd"]);
+        let found: Vec<(&str, &str, usize, &str)> = pairs.iter()
+            .map(|pair| (pair.first.as_str(), pair.second.as_str(), pair.index, pair.common.as_str()))
+            .collect();
+        assert_eq!(found, vec![
+            ("abcd", "abce", 3, "abc"),
+            ("abcd", "xbcd", 0, "bcd"),
+            // A repeated ID is not a near duplicate of itself, but matches the same IDs again.
+            ("abce", "abcd", 3, "abc"),
+            ("xbcd", "abcd", 0, "bcd"),
+            ("abcd", "abzd", 2, "abd"),
+        ]);
+    }
+
+    #[test]
+    fn ignores_different_lengths() {
+        assert!(near_duplicates(&["abc", "abcd", "ab"]).is_empty());
+        assert!(near_duplicates::<&str>(&[]).is_empty());
+        assert_eq!(off_by_one_hashed(&["abc", "abcd", "ab"]), None);
+    }
+
+    #[test]
+    fn hashed_matches_first_pair() {
+        // Identical IDs and transpositions are not near duplicates.
+        assert_eq!(off_by_one_hashed(&["abcd", "abcd", "bacd", "abdc"]), None);
+        assert_eq!(off_by_one_hashed(&["abcd", "abcd", "abce"]), Some(String::from("abc")));
+        assert_eq!(off_by_one_hashed(&["wxyz", "abcd", "xbcd", "wxya"]), Some(String::from("bcd")));
+        assert_eq!(off_by_one_hashed::<&str>(&[]), None);
+    }
+
+    #[test]
+    fn hashed_handles_long_ids() {
+        let long = |c| format!("{}{}{}", "a".repeat(5000), c, "b".repeat(5000));
+        let ids = vec![long('x'), long('x'), "a".repeat(10001), long('y')];
+        assert_eq!(off_by_one_hashed(&ids), Some(format!("{}{}", "a".repeat(5000), "b".repeat(5000))));
+        // Multi-byte characters count as one position each.
+        assert_eq!(off_by_one_hashed(&["añb", "aéb"]), Some(String::from("ab")));
+    }
+}
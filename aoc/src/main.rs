@@ -0,0 +1,205 @@
+extern crate day_five_2018;
+extern crate day_four_2018;
+extern crate day_one_2018;
+extern crate day_three_2018;
+extern crate day_two_2018;
+extern crate input;
+extern crate solution;
+
+mod memory;
+mod registry;
+mod report;
+mod scaffold;
+mod timing;
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+use input::InputCache;
+use memory::CountingAllocator;
+use report::Report;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "Usage:
+    aoc run <year> <day> [input-file] [--inputs <dir>] [--name <name>] [--time] [--format text|json]
+    aoc new <year> <day> [--inputs <dir>]";
+
+/// The ways `aoc run` can print its results.
+#[derive(PartialEq)]
+enum Format {
+    Text,
+    Json
+}
+
+/// Options accepted by `aoc run`.
+struct RunOptions {
+    year: u32,
+    day: u32,
+    /// An explicit input file, used instead of the input cache.
+    input: Option<String>,
+    /// The input cache directory, if given with `--inputs`.
+    inputs: Option<String>,
+    /// The name of the cached input to use.
+    name: String,
+    time: bool,
+    format: Format,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunOptions, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut input = None;
+    let mut time = false;
+    let mut format = Format::Text;
+    let mut inputs = None;
+    let mut name = String::from(input::DEFAULT_NAME);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--time" {
+            time = true;
+        } else if arg == "--inputs" {
+            inputs = Some(args.next().ok_or("Missing input directory")?.clone());
+        } else if arg == "--name" {
+            name = args.next().ok_or("Missing input name")?.clone();
+        } else if arg == "--format" {
+            format = match args.next().map(|s| s.as_str()) {
+                Some("text") => Format::Text,
+                Some("json") => Format::Json,
+                Some(other) => return Err(format!("Unknown format {}", other)),
+                None => return Err(String::from("Missing format"))
+            };
+        } else if arg.starts_with("--") {
+            return Err(format!("Unknown option {}", arg));
+        } else if year.is_none() {
+            year = Some(parse_year(arg)?);
+        } else if day.is_none() {
+            day = Some(parse_day(arg)?);
+        } else if input.is_none() {
+            input = Some(arg.clone());
+        } else {
+            return Err(format!("Unexpected argument {}", arg));
+        }
+    }
+    match (year, day) {
+        (Some(year), Some(day)) => Ok(RunOptions { year, day, input, inputs, name, time, format }),
+        (None, _) => Err(String::from("Missing year")),
+        (_, None) => Err(String::from("Missing day"))
+    }
+}
+
+/// Options accepted by `aoc new`.
+struct NewOptions {
+    year: u32,
+    day: u32,
+    /// The input cache directory, if given with `--inputs`.
+    inputs: Option<String>,
+}
+
+fn parse_new_args(args: &[String]) -> Result<NewOptions, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut inputs = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--inputs" {
+            inputs = Some(args.next().ok_or("Missing input directory")?.clone());
+        } else if arg.starts_with("--") {
+            return Err(format!("Unknown option {}", arg));
+        } else if year.is_none() {
+            year = Some(parse_year(arg)?);
+        } else if day.is_none() {
+            day = Some(parse_day(arg)?);
+        } else {
+            return Err(format!("Unexpected argument {}", arg));
+        }
+    }
+    match (year, day) {
+        (Some(year), Some(day)) => Ok(NewOptions { year, day, inputs }),
+        (None, _) => Err(String::from("Missing year")),
+        (_, None) => Err(String::from("Missing day"))
+    }
+}
+
+fn parse_year(arg: &str) -> Result<u32, String> {
+    // Advent of Code started in 2015.
+    match arg.parse::<u32>() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!("Invalid year {}", arg))
+    }
+}
+
+fn parse_day(arg: &str) -> Result<u32, String> {
+    match arg.parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day {}", arg))
+    }
+}
+
+fn input_cache(inputs: &Option<String>) -> InputCache {
+    match *inputs {
+        Some(ref dir) => InputCache::new(dir.as_str()),
+        None => InputCache::from_env()
+    }
+}
+
+fn run(options: RunOptions) -> Result<(), String> {
+    let year = options.year;
+    let day = options.day;
+    let mut solution = registry::solution(year, day)
+        .ok_or_else(|| format!("Day {} of {} has not been solved yet", day, year))?;
+    let cache = input_cache(&options.inputs);
+    let (input, expected) = match options.input {
+        Some(ref path) => (input::load_file(path.as_str()), (None, None)),
+        None => (cache.load(year, day, &options.name), cache.answers(year, day, &options.name))
+    };
+    let input = input.map_err(|e| e.to_string())?;
+
+    let (parsed, parse_timing) = timing::measure(|| solution.parse(&input));
+    parsed.map_err(|e| format!("Unable to parse input for day {}: {}", day, e))?;
+    let (part_one, part_one_timing) = timing::measure(|| solution.part_one());
+    let (part_two, part_two_timing) = timing::measure(|| solution.part_two());
+
+    let report = Report {
+        year,
+        day,
+        part_one,
+        part_two,
+        expected,
+        timings: if options.time {
+            Some(vec![("parse", parse_timing), ("part1", part_one_timing), ("part2", part_two_timing)])
+        } else {
+            None
+        },
+    };
+    match options.format {
+        Format::Text => print!("{}", report.to_text()),
+        Format::Json => println!("{}", report.to_json())
+    }
+    Ok(())
+}
+
+fn new(options: NewOptions) -> Result<(), String> {
+    let cache = input_cache(&options.inputs);
+    let written = scaffold::new_day(Path::new("."), &cache, options.year, options.day)?;
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    println!("Rebuild the runner to run this day with `aoc run {} {}`", options.year, options.day);
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let result = match args.get(1).map(|s| s.as_str()) {
+        Some("run") => parse_run_args(&args[2..]).and_then(run),
+        Some("new") => parse_new_args(&args[2..]).and_then(new),
+        _ => Err(String::from(USAGE))
+    };
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,14 @@
+use solution::Solution;
+
+/// Returns a fresh solution for the given year and day, or `None` if that day hasn't been solved
+/// yet.
+pub fn solution(year: u32, day: u32) -> Option<Box<dyn Solution>> {
+    match (year, day) {
+        (2018, 1) => Some(Box::new(::day_one_2018::DayOne::new())),
+        (2018, 2) => Some(Box::new(::day_two_2018::DayTwo::new())),
+        (2018, 3) => Some(Box::new(::day_three_2018::DayThree::new())),
+        (2018, 4) => Some(Box::new(::day_four_2018::DayFour::new())),
+        (2018, 5) => Some(Box::new(::day_five_2018::DayFive::new())),
+        _ => None
+    }
+}
@@ -0,0 +1,136 @@
+use timing::StageTiming;
+
+/// The results of running a single day's solution.
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub part_one: String,
+    pub part_two: String,
+    /// The known answers to each part, if they have been recorded.
+    pub expected: (Option<String>, Option<String>),
+    /// The cost of each stage, keyed on the stage name. `None` if timings weren't requested.
+    pub timings: Option<Vec<(&'static str, StageTiming)>>,
+}
+
+impl Report {
+    /// Returns the report as human readable text.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{} Day {}\nPart 1: {}{}\nPart 2: {}{}\n",
+                               self.year, self.day, self.part_one, verdict(&self.part_one, &self.expected.0),
+                               self.part_two, verdict(&self.part_two, &self.expected.1));
+        if let Some(ref timings) = self.timings {
+            text.push_str(&format!("\n{:<8} {:>12} {:>17} {:>17}\n", "stage", "time", "allocations", "peak heap"));
+            for &(name, stage) in timings {
+                text.push_str(&format!("{:<8} {}\n", name, stage));
+            }
+        }
+        text
+    }
+
+    /// Returns the report as a single JSON object of the form
+    /// `{ "year": 2018, "day": 4, "part1": ..., "part2": ..., "correct": ..., "timings": ... }`.
+    ///
+    /// Answers that are integers are written as JSON numbers, and all other answers as strings.
+    /// `correct` maps each part to whether its answer matches the known answer, or `null` if no
+    /// answer is known.
+    /// `timings` is `null` unless timings were requested, in which case it maps each stage name
+    /// to its wall time in nanoseconds, allocation count and peak heap size in bytes.
+    pub fn to_json(&self) -> String {
+        let timings = match self.timings {
+            Some(ref timings) => {
+                let stages: Vec<String> = timings.iter().map(|&(name, stage)| {
+                    format!("{}: {{ \"nanos\": {}, \"allocations\": {}, \"peak_bytes\": {} }}",
+                            json_string(name), stage.elapsed.as_nanos(), stage.allocations, stage.peak_bytes)
+                }).collect();
+                format!("{{ {} }}", stages.join(", "))
+            },
+            None => String::from("null")
+        };
+        let correct = |answer: &str, expected: &Option<String>| match *expected {
+            Some(ref expected) => (answer == expected).to_string(),
+            None => String::from("null")
+        };
+        format!("{{ \"year\": {}, \"day\": {}, \"part1\": {}, \"part2\": {}, \
+                   \"correct\": {{ \"part1\": {}, \"part2\": {} }}, \"timings\": {} }}",
+                self.year, self.day, json_answer(&self.part_one), json_answer(&self.part_two),
+                correct(&self.part_one, &self.expected.0), correct(&self.part_two, &self.expected.1), timings)
+    }
+}
+
+fn verdict(answer: &str, expected: &Option<String>) -> String {
+    match *expected {
+        Some(ref expected) if answer == expected => String::from(" (correct)"),
+        Some(ref expected) => format!(" (incorrect, expected {})", expected),
+        None => String::new()
+    }
+}
+
+fn json_answer(answer: &str) -> String {
+    match answer.parse::<i64>() {
+        Ok(number) => number.to_string(),
+        Err(_) => json_string(answer)
+    }
+}
+
+fn json_string(string: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in string.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c)
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Report;
+
+    #[test]
+    fn numeric_answers_are_numbers() {
+        let report = Report {
+            year: 2018,
+            day: 4,
+            part_one: String::from("12"),
+            part_two: String::from("-3"),
+            expected: (Some(String::from("12")), None),
+            timings: None,
+        };
+        assert_eq!(report.to_json(), "{ \"year\": 2018, \"day\": 4, \"part1\": 12, \"part2\": -3, \
+                                      \"correct\": { \"part1\": true, \"part2\": null }, \"timings\": null }");
+    }
+
+    #[test]
+    fn text_answers_are_escaped_strings() {
+        let report = Report {
+            year: 2018,
+            day: 2,
+            part_one: String::from("1"),
+            part_two: String::from("a\"b\\c"),
+            expected: (None, Some(String::from("abc"))),
+            timings: None,
+        };
+        assert_eq!(report.to_json(), "{ \"year\": 2018, \"day\": 2, \"part1\": 1, \"part2\": \"a\\\"b\\\\c\", \
+                                      \"correct\": { \"part1\": null, \"part2\": false }, \"timings\": null }");
+    }
+
+    #[test]
+    fn text_marks_known_answers() {
+        let report = Report {
+            year: 2018,
+            day: 1,
+            part_one: String::from("3"),
+            part_two: String::from("4"),
+            expected: (Some(String::from("3")), Some(String::from("5"))),
+            timings: None,
+        };
+        assert_eq!(report.to_text(), "2018 Day 1\nPart 1: 3 (correct)\nPart 2: 4 (incorrect, expected 5)\n");
+    }
+}
@@ -0,0 +1,202 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use input::InputCache;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs");
+
+const NUMBER_WORDS: [&str; 25] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+    "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+    "twenty", "twenty-one", "twenty-two", "twenty-three", "twenty-four", "twenty-five"];
+
+/// The names a day's crate goes by in different contexts.
+#[derive(Debug, PartialEq)]
+pub struct DayNames {
+    /// The directory holding the crate, relative to the workspace root, e.g. `2018/day-seven`.
+    pub dir: String,
+    /// The package name, e.g. `day-seven-2018`.
+    pub package: String,
+    /// The crate name as used in Rust code, e.g. `day_seven_2018`.
+    pub ident: String,
+    /// The name of the type implementing `Solution`, e.g. `DaySeven`.
+    pub type_name: String,
+}
+
+impl DayNames {
+    /// Returns the names for the given day of the given year. `day` must be between 1 and 25.
+    pub fn new(year: u32, day: u32) -> DayNames {
+        let word = NUMBER_WORDS[(day - 1) as usize];
+        let mut type_name = String::from("Day");
+        for piece in word.split('-') {
+            let mut chars = piece.chars();
+            if let Some(first) = chars.next() {
+                type_name.extend(first.to_uppercase());
+                type_name.push_str(chars.as_str());
+            }
+        }
+        DayNames {
+            dir: format!("{}/day-{}", year, word),
+            package: format!("day-{}-{}", word, year),
+            ident: format!("day_{}_{}", word.replace('-', "_"), year),
+            type_name,
+        }
+    }
+
+    fn fill(&self, template: &str, year: u32, day: u32) -> String {
+        template.replace("{{crate}}", &self.package)
+            .replace("{{ident}}", &self.ident)
+            .replace("{{struct}}", &self.type_name)
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", NUMBER_WORDS[(day - 1) as usize])
+    }
+}
+
+/// Generates a crate for the given day of the given year within the workspace at `root`,
+/// registers it with the workspace and the runner, and creates empty input, answer and example
+/// files for it in `cache`.
+///
+/// # Returns
+///
+/// * `Ok(paths)` listing every file created or modified.
+/// * `Err(message)` if the day already exists or the workspace couldn't be updated. Nothing is
+///   written in this case unless the failure happened partway through writing files.
+pub fn new_day(root: &Path, cache: &InputCache, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let names = DayNames::new(year, day);
+    let crate_dir = root.join(&names.dir);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    // Work out every edit to the existing files up front, so that nothing is written if any of
+    // them can't be made.
+    let workspace_path = root.join("Cargo.toml");
+    let runner_manifest_path = root.join("aoc").join("Cargo.toml");
+    let runner_main_path = root.join("aoc").join("src").join("main.rs");
+    let registry_path = root.join("aoc").join("src").join("registry.rs");
+    // New members go after the other days of the same year, or after the latest year if this is
+    // the first day of a new year.
+    let workspace = read(&workspace_path)?;
+    let year_prefix = format!("    \"{}/", year);
+    let member_prefix = if workspace.lines().any(|l| l.starts_with(&year_prefix)) { year_prefix.as_str() } else { "    \"20" };
+    let workspace = insert_after_last(&workspace, member_prefix, &format!("    \"{}\",", names.dir))?;
+    let runner_manifest = insert_after_last(&read(&runner_manifest_path)?, "day-",
+                                            &format!("{} = {{ path = \"../{}\" }}", names.package, names.dir))?;
+    let runner_main = insert_sorted(&read(&runner_main_path)?, "extern crate day_",
+                                    &format!("extern crate {};", names.ident))?;
+    let registry = insert_before(&read(&registry_path)?, "        _ => None",
+                                 &format!("        ({}, {}) => Some(Box::new(::{}::{}::new())),",
+                                          year, day, names.ident, names.type_name))?;
+
+    let mut written = Vec::new();
+    let src_dir = crate_dir.join("src");
+    fs::create_dir_all(&src_dir).map_err(|e| format!("Unable to create {}: {}", src_dir.display(), e))?;
+    write(&crate_dir.join("Cargo.toml"), &names.fill(CARGO_TEMPLATE, year, day), &mut written)?;
+    write(&src_dir.join("lib.rs"), &names.fill(LIB_TEMPLATE, year, day), &mut written)?;
+    write(&src_dir.join("main.rs"), &names.fill(MAIN_TEMPLATE, year, day), &mut written)?;
+    write(&workspace_path, &workspace, &mut written)?;
+    write(&runner_manifest_path, &runner_manifest, &mut written)?;
+    write(&runner_main_path, &runner_main, &mut written)?;
+    write(&registry_path, &registry, &mut written)?;
+
+    let day_dir = cache.day_dir(year, day);
+    fs::create_dir_all(&day_dir).map_err(|e| format!("Unable to create {}: {}", day_dir.display(), e))?;
+    for name in ["input", "example"].iter() {
+        for path in [cache.path(year, day, name), cache.answers_path(year, day, name)].iter() {
+            if !path.exists() {
+                write(path, "", &mut written)?;
+            }
+        }
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str, written: &mut Vec<PathBuf>) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Unable to write {}: {}", path.display(), e))?;
+    written.push(path.to_path_buf());
+    Ok(())
+}
+
+/// Inserts `line` after the last line of `text` starting with `prefix`.
+fn insert_after_last(text: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        return Err(format!("`{}` is already present", line.trim()));
+    }
+    let index = lines.iter().rposition(|l| l.starts_with(prefix))
+        .ok_or_else(|| format!("Unable to find a line starting with `{}`", prefix.trim()))?;
+    lines.insert(index + 1, line);
+    Ok(join_lines(&lines, text))
+}
+
+/// Inserts `line` among the consecutive lines of `text` starting with `prefix`, keeping them sorted.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        return Err(format!("`{}` is already present", line.trim()));
+    }
+    let first = lines.iter().position(|l| l.starts_with(prefix))
+        .ok_or_else(|| format!("Unable to find a line starting with `{}`", prefix.trim()))?;
+    let mut index = first;
+    while index < lines.len() && lines[index].starts_with(prefix) && lines[index] < line {
+        index += 1;
+    }
+    lines.insert(index, line);
+    Ok(join_lines(&lines, text))
+}
+
+/// Inserts `line` before the first line of `text` equal to `marker`.
+fn insert_before(text: &str, marker: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let index = lines.iter().position(|l| *l == marker)
+        .ok_or_else(|| format!("Unable to find `{}`", marker.trim()))?;
+    lines.insert(index, line);
+    Ok(join_lines(&lines, text))
+}
+
+fn join_lines(lines: &[&str], original: &str) -> String {
+    let mut joined = lines.join("\n");
+    if original.ends_with('\n') {
+        joined.push('\n');
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::insert_after_last;
+    use super::insert_sorted;
+    use super::DayNames;
+
+    #[test]
+    fn names_for_compound_numbers() {
+        assert_eq!(DayNames::new(2019, 21), DayNames {
+            dir: String::from("2019/day-twenty-one"),
+            package: String::from("day-twenty-one-2019"),
+            ident: String::from("day_twenty_one_2019"),
+            type_name: String::from("DayTwentyOne"),
+        });
+    }
+
+    #[test]
+    fn inserts_after_last_member() {
+        let text = "members = [\n    \"2018/day-one\",\n    \"2018/day-two\",\n    \"shared/trie\",\n]\n";
+        assert_eq!(insert_after_last(text, "    \"2018/", "    \"2018/day-three\",").unwrap(),
+                   "members = [\n    \"2018/day-one\",\n    \"2018/day-two\",\n    \"2018/day-three\",\n    \"shared/trie\",\n]\n");
+        assert!(insert_after_last(text, "    \"2018/", "    \"2018/day-two\",").is_err());
+    }
+
+    #[test]
+    fn inserts_in_sorted_position() {
+        let text = "extern crate day_five_2018;\nextern crate day_two_2018;\nextern crate input;";
+        assert_eq!(insert_sorted(text, "extern crate day_", "extern crate day_seven_2018;").unwrap(),
+                   "extern crate day_five_2018;\nextern crate day_seven_2018;\nextern crate day_two_2018;\nextern crate input;");
+    }
+}
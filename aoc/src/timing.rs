@@ -0,0 +1,68 @@
+use std::fmt;
+use std::time::Duration;
+use std::time::Instant;
+
+use memory;
+
+/// The cost of running a single stage (parse, part one or part two) of a solution.
+#[derive(Clone, Copy, Debug)]
+pub struct StageTiming {
+    /// Wall time spent in the stage.
+    pub elapsed: Duration,
+    /// The number of allocations made during the stage.
+    pub allocations: usize,
+    /// The most bytes live on the heap at any point during the stage.
+    pub peak_bytes: usize,
+}
+
+/// Runs `stage`, measuring how long it takes and how much it allocates.
+///
+/// # Returns
+///
+/// * The value returned by `stage`, along with its `StageTiming`.
+pub fn measure<T, F: FnOnce() -> T>(stage: F) -> (T, StageTiming) {
+    memory::reset_peak();
+    let before = memory::stats();
+    let start = Instant::now();
+    let result = stage();
+    let elapsed = start.elapsed();
+    let after = memory::stats();
+    (result, StageTiming {
+        elapsed,
+        allocations: after.allocations - before.allocations,
+        peak_bytes: after.peak_bytes,
+    })
+}
+
+impl fmt::Display for StageTiming {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>12} {:>10} allocs {:>12} peak",
+               format_duration(self.elapsed), self.allocations, format_bytes(self.peak_bytes))
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.3}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}B", bytes)
+    } else {
+        format!("{:.1}{}", size, units[unit])
+    }
+}
@@ -0,0 +1,47 @@
+extern crate solution;
+
+use solution::Solution;
+
+/// Solution to day {{day}} of {{year}}.
+pub struct {{struct}} {
+    lines: Vec<String>,
+}
+
+impl {{struct}} {
+    pub fn new() -> {{struct}} {
+        {{struct}} { lines: Vec::new() }
+    }
+}
+
+impl Default for {{struct}} {
+    fn default() -> {{struct}} {
+        {{struct}}::new()
+    }
+}
+
+impl Solution for {{struct}} {
+    fn parse(&mut self, input: &str) -> Result<(), String> {
+        self.lines = input.lines().map(|line| line.to_string()).collect();
+        Ok(())
+    }
+
+    fn part_one(&mut self) -> String {
+        self.lines.len().to_string()
+    }
+
+    fn part_two(&mut self) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solution::Solution;
+    use super::{{struct}};
+
+    #[test]
+    fn parses_input() {
+        let mut day = {{struct}}::new();
+        assert!(day.parse("").is_ok());
+    }
+}
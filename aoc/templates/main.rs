@@ -0,0 +1,19 @@
+extern crate {{ident}};
+extern crate solution;
+
+use std::env;
+use std::fs;
+
+use {{ident}}::{{struct}};
+use solution::Solution;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    assert_eq!(args.len(), 2, "Incorrect number of args");
+    let input = fs::read_to_string(&args[1]).expect("file not found");
+
+    let mut day = {{struct}}::new();
+    day.parse(&input).unwrap();
+    println!("Part 1: {}", day.part_one());
+    println!("Part 2: {}", day.part_two());
+}
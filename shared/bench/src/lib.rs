@@ -0,0 +1,54 @@
+//! Helpers for the benchmarks and randomised tests, shared as a dev-dependency.
+
+use std::time::Duration;
+use std::time::Instant;
+
+/// The number of times `fastest` runs a function.
+pub const RUNS: u32 = 10;
+
+/// Runs `f` `RUNS` times, and returns the time the fastest run took along with the last result.
+pub fn fastest<T, F: FnMut() -> T>(mut f: F) -> (Duration, T) {
+    let mut best = None;
+    let mut result = None;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        result = Some(f());
+        let elapsed = start.elapsed();
+        best = Some(best.map_or(elapsed, |best: Duration| best.min(elapsed)));
+    }
+    (best.unwrap(), result.unwrap())
+}
+
+/// A linear congruential generator, which keeps generated inputs the same from run to run.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    /// Returns a generator starting from `seed`.
+    pub fn new(seed: u64) -> Lcg {
+        Lcg { state: seed }
+    }
+
+    /// Returns a pseudo-random number below `limit`, which must not be 0.
+    pub fn below(&mut self, limit: usize) -> usize {
+        self.state = self.state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        (self.state >> 33) as usize % limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lcg;
+
+    #[test]
+    fn repeats_from_the_same_seed() {
+        let numbers = |seed| {
+            let mut lcg = Lcg::new(seed);
+            (0..20).map(|_| lcg.below(100)).collect::<Vec<usize>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|&n| n < 100));
+    }
+}
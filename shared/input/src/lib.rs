@@ -0,0 +1,200 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The environment variable that overrides the default input cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_INPUTS";
+/// The cache directory used when neither `--inputs` nor `AOC_INPUTS` is given.
+pub const DEFAULT_CACHE_DIR: &str = "inputs";
+/// The name of the input used when no other name is requested.
+pub const DEFAULT_NAME: &str = "input";
+
+/// Describes why a puzzle input couldn't be loaded.
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no input file at `path`. `available` lists the names of the inputs that do exist
+    /// for the same day.
+    Missing { path: PathBuf, available: Vec<String> },
+    /// The input file at `path` exists, but contains nothing other than whitespace.
+    Empty { path: PathBuf },
+    /// The input file at `path` couldn't be read.
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputError::Missing { ref path, ref available } => {
+                write!(f, "Input {} does not exist", path.display())?;
+                if available.is_empty() {
+                    write!(f, " (no inputs are cached for this day)")
+                } else {
+                    write!(f, " (available inputs: {})", available.join(", "))
+                }
+            },
+            InputError::Empty { ref path } => write!(f, "Input {} is empty", path.display()),
+            InputError::Io { ref path, ref error } => write!(f, "Unable to read {}: {}", path.display(), error)
+        }
+    }
+}
+
+/// A directory of puzzle inputs, laid out as `<root>/<year>/day-<DD>/<name>.txt`.
+///
+/// Each day may have any number of named inputs (e.g. one per teammate), with `input` being
+/// the name used by default. The known answers for an input may be stored next to it in
+/// `<name>.answers`, with part one's answer on the first line and part two's on the second.
+pub struct InputCache {
+    root: PathBuf,
+}
+
+impl InputCache {
+    /// Returns a cache rooted at `root`.
+    pub fn new<P: Into<PathBuf>>(root: P) -> InputCache {
+        InputCache { root: root.into() }
+    }
+
+    /// Returns a cache rooted at the directory named by `AOC_INPUTS`, or at `inputs` if it isn't
+    /// set.
+    pub fn from_env() -> InputCache {
+        match env::var(CACHE_DIR_VAR) {
+            Ok(ref dir) if !dir.is_empty() => InputCache::new(dir.as_str()),
+            _ => InputCache::new(DEFAULT_CACHE_DIR)
+        }
+    }
+
+    /// Returns the directory holding every input for the given day.
+    pub fn day_dir(&self, year: u32, day: u32) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("day-{:02}", day))
+    }
+
+    /// Returns the path at which the named input for the given day is stored.
+    pub fn path(&self, year: u32, day: u32, name: &str) -> PathBuf {
+        self.day_dir(year, day).join(format!("{}.txt", name))
+    }
+
+    /// Returns the path at which the answers to the named input for the given day are stored.
+    pub fn answers_path(&self, year: u32, day: u32, name: &str) -> PathBuf {
+        self.day_dir(year, day).join(format!("{}.answers", name))
+    }
+
+    /// Returns the known answers to each part of the named input for the given day. A part's
+    /// answer is `None` if it hasn't been recorded.
+    pub fn answers(&self, year: u32, day: u32, name: &str) -> (Option<String>, Option<String>) {
+        let contents = fs::read_to_string(self.answers_path(year, day, name)).unwrap_or_default();
+        let mut answers = contents.lines()
+            .map(|line| line.trim())
+            .map(|line| if line.is_empty() { None } else { Some(line.to_string()) });
+        (answers.next().and_then(|a| a), answers.next().and_then(|a| a))
+    }
+
+    /// Returns the sorted names of every input cached for the given day.
+    pub fn names(&self, year: u32, day: u32) -> Vec<String> {
+        let mut names = Vec::new();
+        if let Ok(entries) = fs::read_dir(self.day_dir(year, day)) {
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+                if path.extension().is_some_and(|ext| ext == "txt") {
+                    if let Some(stem) = path.file_stem() {
+                        names.push(stem.to_string_lossy().into_owned());
+                    }
+                }
+            }
+        }
+        names.sort();
+        names
+    }
+
+    /// Loads the named input for the given day.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(contents)` if the input exists and isn't empty.
+    /// * `Err(InputError)` describing why the input couldn't be used otherwise.
+    pub fn load(&self, year: u32, day: u32, name: &str) -> Result<String, InputError> {
+        let path = self.path(year, day, name);
+        if !path.is_file() {
+            return Err(InputError::Missing { path, available: self.names(year, day) });
+        }
+        load_file(path)
+    }
+}
+
+/// Loads an input from an explicit path, outside of any cache.
+pub fn load_file<P: Into<PathBuf>>(path: P) -> Result<String, InputError> {
+    let path = path.into();
+    match fs::read_to_string(&path) {
+        Ok(ref contents) if contents.trim().is_empty() => Err(InputError::Empty { path }),
+        Ok(contents) => Ok(contents),
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => {
+            Err(InputError::Missing { path, available: Vec::new() })
+        },
+        Err(error) => Err(InputError::Io { path, error })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use super::InputCache;
+    use super::InputError;
+
+    fn temp_cache(test: &str) -> (PathBuf, InputCache) {
+        let root = env::temp_dir().join(format!("aoc-input-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("2018").join("day-04")).unwrap();
+        (root.clone(), InputCache::new(root))
+    }
+
+    #[test]
+    fn loads_named_inputs() {
+        let (root, cache) = temp_cache("named");
+        fs::write(cache.path(2018, 4, "input"), "one\n").unwrap();
+        fs::write(cache.path(2018, 4, "alice"), "two\n").unwrap();
+
+        assert_eq!(cache.load(2018, 4, "input").unwrap(), "one\n");
+        assert_eq!(cache.load(2018, 4, "alice").unwrap(), "two\n");
+        assert_eq!(cache.names(2018, 4), vec!["alice".to_string(), "input".to_string()]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn loads_answers() {
+        let (root, cache) = temp_cache("answers");
+        assert_eq!(cache.answers(2018, 4, "input"), (None, None));
+        fs::write(cache.answers_path(2018, 4, "input"), "\n42\n").unwrap();
+        assert_eq!(cache.answers(2018, 4, "input"), (None, Some(String::from("42"))));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn missing_input_lists_alternatives() {
+        let (root, cache) = temp_cache("missing");
+        fs::write(cache.path(2018, 4, "alice"), "two\n").unwrap();
+
+        match cache.load(2018, 4, "bob") {
+            Err(InputError::Missing { available, .. }) => assert_eq!(available, vec!["alice".to_string()]),
+            _ => panic!("expected a missing input")
+        }
+        match cache.load(2018, 5, "input") {
+            Err(InputError::Missing { available, .. }) => assert!(available.is_empty()),
+            _ => panic!("expected a missing input")
+        }
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn empty_input_is_an_error() {
+        let (root, cache) = temp_cache("empty");
+        fs::write(cache.path(2018, 4, "input"), "\n  \n").unwrap();
+
+        match cache.load(2018, 4, "input") {
+            Err(InputError::Empty { path }) => assert_eq!(path, cache.path(2018, 4, "input")),
+            _ => panic!("expected an empty input")
+        }
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,24 @@
+/// The interface every day's puzzle implements so that it can be driven by the `aoc` runner.
+///
+/// A solution is run in three stages: `parse` is handed the full puzzle input, and then
+/// `part_one` and `part_two` are called (in that order) to produce each part's answer. Keeping
+/// the stages separate lets the runner measure the cost of each one on its own.
+pub trait Solution {
+    /// Parses the puzzle input and stores whatever state the two parts need.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The full contents of the puzzle input.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the input was parsed successfully.
+    /// * `Err(message)` describing the first problem found in the input otherwise.
+    fn parse(&mut self, input: &str) -> Result<(), String>;
+
+    /// Returns the answer to the first part of the puzzle.
+    fn part_one(&mut self) -> String;
+
+    /// Returns the answer to the second part of the puzzle.
+    fn part_two(&mut self) -> String;
+}
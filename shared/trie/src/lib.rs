@@ -200,6 +200,73 @@ impl Trie {
         }
         return None;
     }
+
+    /// Searches for every string in the trie that differs from the input string by exactly one
+    /// character.
+    ///
+    /// Unlike `match_off_by_one`, which only follows the longest prefix of the input that is in
+    /// the trie, this tries the mismatch at every position, so no match is missed. Only strings
+    /// of the same length as the input can match.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - A string to be searched for in this trie.
+    ///
+    /// # Returns
+    ///
+    /// * A vector of `(string, index)` pairs, one for each matching string in the trie, where
+    ///   `index` is the position of the character that differs from `val`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use trie::Trie;
+    ///
+    /// let mut trie = Trie::new();
+    /// trie.insert(String::from("axcd"));
+    /// trie.insert(String::from("abzz"));
+    /// trie.insert(String::from("abcd"));
+    ///
+    /// let matches = trie.matches_off_by_one("abce");
+    /// assert_eq!(matches, vec![(&String::from("abcd"), 3)]);
+    ///
+    /// let matches = trie.matches_off_by_one("abcd");
+    /// assert_eq!(matches, vec![(&String::from("axcd"), 1)]);
+    /// ```
+    pub fn matches_off_by_one(&self, val: &str) -> Vec<(&String, usize)> {
+        let chars: Vec<char> = val.chars().collect();
+        let mut matches = Vec::new();
+        collect_off_by_one(&self.root, &chars, 0, None, &mut matches);
+        matches
+    }
+}
+
+/// Walks the trie below `node` (which sits at depth `depth`), following `chars` but allowing a
+/// single mismatch. `mismatch` holds the index of the mismatch made so far, if any. Every leaf
+/// reached with exactly one mismatch is added to `matches`.
+fn collect_off_by_one<'a>(node: &'a TrieNode, chars: &[char], depth: usize, mismatch: Option<usize>,
+                          matches: &mut Vec<(&'a String, usize)>) {
+    if depth == chars.len() {
+        if let (Some(ref leaf), Some(index)) = (&node.leaf, mismatch) {
+            matches.push((leaf, index));
+        }
+        return;
+    }
+    match mismatch {
+        // Having already used up the mismatch, only the exact path can match.
+        Some(_) => if let Some(child) = node.children.get(&chars[depth]) {
+            collect_off_by_one(child, chars, depth + 1, mismatch, matches);
+        },
+        None => {
+            // Visit children in order so that matches come out in a predictable order.
+            let mut children: Vec<&TrieNode> = node.children.values().collect();
+            children.sort_by_key(|child| child.val);
+            for child in children {
+                let mismatch = if child.val == chars[depth] { None } else { Some(depth) };
+                collect_off_by_one(child, chars, depth + 1, mismatch, matches);
+            }
+        }
+    }
 }
 
 fn identity<T>(t: T) -> T { t }
@@ -280,6 +347,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_all_off_by_one() {
+        let mut trie = Trie::new();
+        trie.insert(String::from("abcdef"));
+        trie.insert(String::from("abcdeg"));
+        trie.insert(String::from("xbcdef"));
+        trie.insert(String::from("abcde"));
+
+        let search_str = String::from("abcdef");
+        let matches = trie.matches_off_by_one(&search_str);
+        assert_eq!(matches, vec![(&String::from("abcdeg"), 5), (&String::from("xbcdef"), 0)]);
+    }
+
     #[test]
     fn test_off_by_one_end() {
         let mut trie = Trie::new();